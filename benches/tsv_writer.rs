@@ -0,0 +1,45 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use std::hint::black_box;
+use table_extractor::writer::TsvWriter;
+use table_extractor::{Table, Writer};
+
+const ROWS: usize = 100_000;
+
+fn large_table() -> Table {
+    let headers = ["id", "name", "email", "country", "score"]
+        .iter()
+        .map(|h| h.to_string())
+        .collect();
+    let rows = (0..ROWS)
+        .map(|i| {
+            vec![
+                i.to_string(),
+                format!("User {}", i),
+                format!("user{}@example.com", i),
+                ["US", "UK", "FR", "日本"][i % 4].to_string(),
+                format!("{}.{}", i % 100, i % 10),
+            ]
+        })
+        .collect();
+    Table::new(headers, rows)
+}
+
+fn bench_tsv_writer(c: &mut Criterion) {
+    let table = large_table();
+    let writer = TsvWriter::default();
+    let bytes = table.write_to_string(&writer).unwrap().len();
+
+    let mut group = c.benchmark_group("tsv_writer");
+    group.throughput(Throughput::Bytes(bytes as u64));
+    group.bench_function("write_100k_rows", |b| {
+        b.iter_batched_ref(
+            || Vec::with_capacity(bytes),
+            |output| writer.write(black_box(&table), output).unwrap(),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_tsv_writer);
+criterion_main!(benches);
@@ -2,14 +2,20 @@ use crate::Format;
 use regex::Regex;
 use std::sync::OnceLock;
 
-/// Number of lines to examine for format detection
-const FORMAT_DETECTION_LINE_LIMIT: usize = 30;
+/// Default number of non-empty lines examined for format detection
+pub const FORMAT_DETECTION_LINE_LIMIT: usize = 30;
+
+/// Maximum number of leading bytes examined for format detection, whatever
+/// the line limit, so blank-line padding or one huge line can't force a scan
+/// of the whole input
+pub const FORMAT_DETECTION_BYTE_LIMIT: usize = 256 * 1024;
 
 // Compile regexes once at startup for performance
 // These are used for format auto-detection
 static MYSQL_BORDER: OnceLock<Regex> = OnceLock::new();
 static POSTGRES_SEP: OnceLock<Regex> = OnceLock::new();
 static MARKDOWN_SEP: OnceLock<Regex> = OnceLock::new();
+static ORG_RULE: OnceLock<Regex> = OnceLock::new();
 
 fn get_mysql_border() -> &'static Regex {
     MYSQL_BORDER.get_or_init(|| Regex::new(r"^\+[-+]+\+$").expect("Invalid MySQL border regex"))
@@ -21,46 +27,315 @@ fn get_postgres_sep() -> &'static Regex {
     })
 }
 
+fn get_org_rule() -> &'static Regex {
+    ORG_RULE.get_or_init(|| Regex::new(r"^\s*\|-+(\+-+)+\|?\s*$").expect("Invalid org rule regex"))
+}
+
 fn get_markdown_sep() -> &'static Regex {
     MARKDOWN_SEP.get_or_init(|| {
         Regex::new(r"^\s*\|(?:\s*:?\s*-+\s*:?\s*\|)+").expect("Invalid Markdown separator regex")
     })
 }
 
-/// Detects the table format from input text
+/// Detects the table format from input text.
+///
+/// When the input matches several signatures, the first match in this
+/// order wins, so structural formats beat delimiters found in their data:
+///
+/// 1. JSON (`[` followed by `[`, `{` or `]`)
+/// 2. MySQL (`+---+` borders)
+/// 3. PostgreSQL (`---+---` separator)
+/// 4. Org (`|---+---|` rule)
+/// 5. Markdown (`|---|` separator)
+/// 6. PSV (consistent pipes, no separator row)
+/// 7. TSV (tabs)
+/// 8. CSV (everything else)
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::detector::detect_format;
+/// use table_extractor::Format;
+///
+/// // Tabs in the data don't turn a psql table into TSV
+/// let input = " id | note\n----+------\n  1 | a\tb";
+/// assert_eq!(detect_format(input), Format::PostgreSQL);
+/// ```
 pub fn detect_format(input: &str) -> Format {
-    let lines: Vec<&str> = input
+    detect_format_with_confidence(input).0
+}
+
+/// Detects the table format along with a confidence score from 0.0 to 1.0.
+///
+/// A structural format scores at least 0.5 for its signature line, plus
+/// credit for the shape of the rest of the table: a separator on the second
+/// line, rows framed by pipes, a consistent column count. A delimited format
+/// scores by how many lines share the first line's delimiter count, so bare
+/// CSV never scores above 0.9, and CSV with no delimiter at all, which is
+/// only the fallback, scores 0.1. Blank input scores 0.0.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::detector::detect_format_with_confidence;
+/// use table_extractor::Format;
+///
+/// let (format, confidence) = detect_format_with_confidence("+----+\n| id |\n+----+");
+/// assert_eq!(format, Format::MySQL);
+/// assert_eq!(confidence, 1.0);
+///
+/// let (format, confidence) = detect_format_with_confidence("apple\nbanana");
+/// assert_eq!(format, Format::CSV);
+/// assert!(confidence < 0.5);
+/// ```
+pub fn detect_format_with_confidence(input: &str) -> (Format, f32) {
+    detect_format_from_lines_with_confidence(input, FORMAT_DETECTION_LINE_LIMIT)
+}
+
+/// Detects the table format, examining at most `max_lines` non-empty lines.
+///
+/// Detection is sample-based: only the first `max_lines` non-empty lines
+/// within the first [`FORMAT_DETECTION_BYTE_LIMIT`] bytes are looked at, so
+/// its cost doesn't grow with the input. Raise the line limit for inputs
+/// whose table starts after a long banner or comment preamble.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::detector::detect_format_from_lines;
+/// use table_extractor::Format;
+///
+/// let input = "Report\n| id |\n|----|\n| 1  |";
+/// assert_eq!(detect_format_from_lines(input, 2), Format::CSV);
+/// assert_eq!(detect_format_from_lines(input, 3), Format::Markdown);
+/// ```
+pub fn detect_format_from_lines(input: &str, max_lines: usize) -> Format {
+    detect_format_from_lines_with_confidence(input, max_lines).0
+}
+
+/// Like [`detect_format_from_lines`], but also returns the confidence score
+/// described under [`detect_format_with_confidence`].
+pub fn detect_format_from_lines_with_confidence(input: &str, max_lines: usize) -> (Format, f32) {
+    let sample = detection_sample(input);
+
+    // Check for JSON: an array opening an array or object
+    if is_json_format(sample) {
+        let closed = if sample.trim_end().ends_with(']') {
+            0.25
+        } else {
+            0.0
+        };
+        return (Format::Json, 0.75 + closed);
+    }
+
+    let lines: Vec<&str> = sample
         .lines()
         .filter(|line| !line.trim().is_empty())
-        .take(FORMAT_DETECTION_LINE_LIMIT)
+        .take(max_lines)
         .collect();
 
     if lines.is_empty() {
-        return Format::CSV; // Default
+        return (Format::CSV, 0.0); // Default
     }
 
+    let is_framed = |line: &str| {
+        let trimmed = line.trim();
+        trimmed.starts_with('|') && trimmed.ends_with('|')
+    };
+    // Separators conventionally sit right under the header
+    let separator_bonus = |sep: &Regex| {
+        if lines.get(1).is_some_and(|line| sep.is_match(line)) {
+            0.25
+        } else {
+            0.0
+        }
+    };
+
     // Check for MySQL format: +---+ or +----+ borders
     if is_mysql_format(&lines) {
-        return Format::MySQL;
+        let shaped = share(&lines, |line| {
+            get_mysql_border().is_match(line.trim()) || is_framed(line)
+        });
+        return (Format::MySQL, 0.5 + 0.5 * shaped);
     }
 
     // Check for PostgreSQL format: dashes and pipes as separator
     if is_postgres_format(&lines) {
-        return Format::PostgreSQL;
+        let rows: Vec<&str> = lines
+            .iter()
+            .copied()
+            .filter(|line| !get_postgres_sep().is_match(line))
+            .collect();
+        let confidence =
+            0.5 + separator_bonus(get_postgres_sep()) + 0.25 * delimiter_consistency(&rows, b'|');
+        return (Format::PostgreSQL, confidence);
+    }
+
+    // Check for org-mode format: |---+---| rule
+    if is_org_format(&lines) {
+        return (Format::Org, 0.5 + 0.5 * share(&lines, is_framed));
     }
 
     // Check for Markdown format: |---|---| pattern
     if is_markdown_format(&lines) {
-        return Format::Markdown;
+        let confidence =
+            0.5 + separator_bonus(get_markdown_sep()) + 0.25 * delimiter_consistency(&lines, b'|');
+        return (Format::Markdown, confidence);
+    }
+
+    // Check for PSV: pipes consistently outnumber every other delimiter
+    if is_psv_format(&lines) {
+        return (Format::Psv, 0.3 + 0.6 * delimiter_consistency(&lines, b'|'));
     }
 
     // Check for TSV: contains tabs
     if is_tsv_format(&lines) {
-        return Format::TSV;
+        return (
+            Format::TSV,
+            0.3 + 0.6 * delimiter_consistency(&lines, b'\t'),
+        );
+    }
+
+    // Default to CSV, which is only a guess when there are no commas
+    let consistency = delimiter_consistency(&lines, b',');
+    let confidence = if consistency > 0.0 {
+        0.3 + 0.6 * consistency
+    } else {
+        0.1
+    };
+    (Format::CSV, confidence)
+}
+
+/// Returns the fraction of `lines` satisfying `predicate`
+fn share(lines: &[&str], predicate: impl Fn(&str) -> bool) -> f32 {
+    if lines.is_empty() {
+        return 0.0;
+    }
+    lines.iter().filter(|line| predicate(line)).count() as f32 / lines.len() as f32
+}
+
+/// Returns the fraction of `lines` with as many unquoted `delimiter`s as the
+/// first line, or 0.0 when the first line has none
+fn delimiter_consistency(lines: &[&str], delimiter: u8) -> f32 {
+    match lines.first().map(|line| count_unquoted(line, delimiter)) {
+        Some(expected) if expected > 0 => {
+            share(lines, |line| count_unquoted(line, delimiter) == expected)
+        }
+        _ => 0.0,
+    }
+}
+
+/// Reports whether the input looks like a plain list rather than a table:
+/// more than one non-empty line and no delimiter or pipe on any of them.
+///
+/// Such input still detects as CSV (a single column named after the first
+/// line), which is rarely what was meant.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::detector::is_plain_lines;
+///
+/// assert!(is_plain_lines("apple\nbanana\ncherry"));
+/// assert!(!is_plain_lines("id,name\n1,Alice"));
+/// ```
+pub fn is_plain_lines(input: &str) -> bool {
+    let lines: Vec<&str> = detection_sample(input)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .take(FORMAT_DETECTION_LINE_LIMIT)
+        .collect();
+
+    lines.len() > 1
+        && lines
+            .iter()
+            .all(|line| !line.bytes().any(|b| SNIFF_CANDIDATES.contains(&b)))
+}
+
+/// Delimiters considered by [`sniff_delimiter`], in order of preference
+const SNIFF_CANDIDATES: [u8; 4] = [b',', b'\t', b';', b'|'];
+
+/// Guesses the field delimiter of delimiter-separated input.
+///
+/// A candidate qualifies when it appears the same, non-zero number of times
+/// on every examined line (ignoring occurrences inside double quotes); the
+/// qualifying candidate with the most occurrences wins. Falls back to a comma
+/// when no candidate is consistent.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::detector::sniff_delimiter;
+///
+/// assert_eq!(sniff_delimiter("id;name\n1;Alice"), b';');
+/// assert_eq!(sniff_delimiter("id|name\n1|Alice"), b'|');
+/// ```
+pub fn sniff_delimiter(input: &str) -> u8 {
+    let lines: Vec<&str> = detection_sample(input)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .take(FORMAT_DETECTION_LINE_LIMIT)
+        .collect();
+
+    let mut best = None;
+    for candidate in SNIFF_CANDIDATES {
+        let mut counts = lines.iter().map(|line| count_unquoted(line, candidate));
+        let Some(first) = counts.next() else {
+            break;
+        };
+        if first == 0 || !counts.all(|count| count == first) {
+            continue;
+        }
+        if best.is_none_or(|(_, count)| first > count) {
+            best = Some((candidate, first));
+        }
     }
 
-    // Default to CSV
-    Format::CSV
+    best.map_or(b',', |(delimiter, _)| delimiter)
+}
+
+/// Returns the leading part of `input` that detection may examine: at most
+/// [`FORMAT_DETECTION_BYTE_LIMIT`] bytes, ending on a line boundary when
+/// there is one so no partial line is judged
+fn detection_sample(input: &str) -> &str {
+    if input.len() <= FORMAT_DETECTION_BYTE_LIMIT {
+        return input;
+    }
+
+    let mut end = FORMAT_DETECTION_BYTE_LIMIT;
+    while !input.is_char_boundary(end) {
+        end -= 1;
+    }
+    let sample = &input[..end];
+    match sample.rfind('\n') {
+        Some(newline) => &sample[..newline],
+        None => sample,
+    }
+}
+
+/// Counts `delimiter` bytes outside double quotes. A pipe escaped as `\|`
+/// is Markdown cell content, so it isn't counted.
+fn count_unquoted(line: &str, delimiter: u8) -> usize {
+    let mut in_quotes = false;
+    let mut escaped = false;
+    line.bytes()
+        .filter(|&b| {
+            let after_backslash = escaped;
+            escaped = b == b'\\' && !after_backslash;
+            if b == b'"' {
+                in_quotes = !in_quotes;
+            }
+            b == delimiter && !in_quotes && !(delimiter == b'|' && after_backslash)
+        })
+        .count()
+}
+
+fn is_json_format(sample: &str) -> bool {
+    // A bare leading `[` isn't enough: CSV headers like `[id],name` start with one
+    sample
+        .trim_start()
+        .strip_prefix('[')
+        .is_some_and(|rest| rest.trim_start().starts_with(['[', '{', ']']))
 }
 
 fn is_mysql_format(lines: &[&str]) -> bool {
@@ -80,11 +355,40 @@ fn is_postgres_format(lines: &[&str]) -> bool {
     lines.iter().any(|line| get_postgres_sep().is_match(line))
 }
 
+fn is_org_format(lines: &[&str]) -> bool {
+    // Org rules join columns with +, which Markdown separators never do
+    lines.iter().any(|line| get_org_rule().is_match(line))
+}
+
 fn is_markdown_format(lines: &[&str]) -> bool {
     // Markdown tables have separator lines like |---|---|
     lines.iter().any(|line| get_markdown_sep().is_match(line))
 }
 
+fn is_psv_format(lines: &[&str]) -> bool {
+    // A dashed separator row, even without outer pipes, means a Markdown-style
+    // table rather than plain pipe-separated data
+    let has_separator = lines.iter().any(|line| {
+        line.contains('-')
+            && line
+                .chars()
+                .all(|c| matches!(c, '-' | ':' | '|') || c.is_whitespace())
+    });
+    let Some(first) = lines.first().filter(|_| !has_separator) else {
+        return false;
+    };
+    let pipes = count_unquoted(first, b'|');
+
+    pipes > 0
+        && lines.iter().all(|line| {
+            count_unquoted(line, b'|') == pipes
+                && SNIFF_CANDIDATES
+                    .iter()
+                    .filter(|&&candidate| candidate != b'|')
+                    .all(|&candidate| count_unquoted(line, candidate) < pipes)
+        })
+}
+
 fn is_tsv_format(lines: &[&str]) -> bool {
     // TSV contains tabs
     let has_tabs = lines.iter().any(|line| line.contains('\t'));
@@ -109,6 +413,43 @@ fn is_tsv_format(lines: &[&str]) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_detect_psv() {
+        assert_eq!(detect_format("id|name\n1|Alice\n2|Bob"), Format::Psv);
+        assert_eq!(detect_format("id|note\n1|\"a, b, c\""), Format::Psv);
+
+        // Commas win when they outnumber the pipes
+        assert_eq!(detect_format("id,name,cmd\n1,Alice,a|b"), Format::CSV);
+        // An inconsistent pipe count isn't a delimiter
+        assert_eq!(detect_format("id|name\n1|a|b"), Format::CSV);
+        // A separator row rules out PSV, with or without outer pipes
+        assert_eq!(
+            detect_format("| id | name |\n|---|---|\n| 1 | Alice |"),
+            Format::Markdown
+        );
+        assert_ne!(detect_format("id|name\n---|---\n1|Alice"), Format::Psv);
+    }
+
+    #[test]
+    fn test_detect_json() {
+        assert_eq!(detect_format("[[\"id\"],[1]]"), Format::Json);
+        assert_eq!(detect_format("\n  [\n  {\"id\": 1}\n]"), Format::Json);
+        assert_eq!(detect_format("[]"), Format::Json);
+
+        // Bracketed CSV headers aren't JSON
+        assert_eq!(detect_format("[id],[name]\n1,Alice"), Format::CSV);
+    }
+
+    #[test]
+    fn test_detect_org() {
+        let input = "| id | name  |\n|----+-------|\n|  1 | Alice |";
+        assert_eq!(detect_format(input), Format::Org);
+
+        // A single-column rule has no join, so it stays Markdown
+        assert_eq!(detect_format("| id |\n|----|\n| 1 |"), Format::Markdown);
+        assert!(!get_org_rule().is_match("|----|------|"));
+    }
+
     #[test]
     fn test_detect_mysql() {
         let input = r#"+----+-------+
@@ -137,6 +478,45 @@ mod tests {
         assert_eq!(detect_format(input), Format::Markdown);
     }
 
+    #[test]
+    fn test_detection_confidence() {
+        let mysql =
+            "+----+-------+\n| id | name  |\n+----+-------+\n|  1 | Alice |\n+----+-------+";
+        let csv = "id,name\n1,Alice";
+
+        let (format, mysql_confidence) = detect_format_with_confidence(mysql);
+        assert_eq!(format, Format::MySQL);
+        let (format, csv_confidence) = detect_format_with_confidence(csv);
+        assert_eq!(format, Format::CSV);
+        assert!(mysql_confidence > csv_confidence);
+
+        // Ragged rows and missing delimiters make the CSV guess shakier
+        let (_, ragged_confidence) = detect_format_with_confidence("id,name\n1,Alice,x\n2");
+        assert!(ragged_confidence < csv_confidence);
+        assert_eq!(
+            detect_format_with_confidence("apple\nbanana"),
+            (Format::CSV, 0.1)
+        );
+        assert_eq!(detect_format_with_confidence("  \n"), (Format::CSV, 0.0));
+
+        // A psql table with the separator on line 2 beats one without
+        let (_, aligned) = detect_format_with_confidence(" id | name\n----+-----\n  1 | a");
+        let (_, shifted) = detect_format_with_confidence(" id | name\n  1 | a\n----+-----");
+        assert!(aligned > shifted);
+
+        // An escaped pipe is cell content, not a column break
+        assert_eq!(
+            detect_format_with_confidence("| id | name |\n|----|------|\n| 1  | A \\| B |"),
+            (Format::Markdown, 1.0)
+        );
+
+        for input in [mysql, csv, "id\tname\n1\tAlice", "| a |\n|---|\n| 1 |"] {
+            let (format, confidence) = detect_format_with_confidence(input);
+            assert_eq!(format, detect_format(input));
+            assert!((0.0..=1.0).contains(&confidence), "{}", input);
+        }
+    }
+
     #[test]
     fn test_detect_tsv() {
         let input = "id\tname\n1\tAlice\n2\tBob";
@@ -149,6 +529,30 @@ mod tests {
         assert_eq!(detect_format(input), Format::CSV);
     }
 
+    #[test]
+    fn test_structural_formats_win_over_tabs() {
+        let mysql =
+            "+----+-------+\n| id | note  |\n+----+-------+\n| 1  | a\tb  |\n+----+-------+";
+        assert_eq!(detect_format(mysql), Format::MySQL);
+
+        let postgres = " id\t| note\n----+------\n 1\t| a\tb\n 2\t| c";
+        assert_eq!(detect_format(postgres), Format::PostgreSQL);
+
+        let markdown = "| id | note |\n|----|------|\n| 1 | a\tb |";
+        assert_eq!(detect_format(markdown), Format::Markdown);
+    }
+
+    #[test]
+    fn test_structural_formats_win_over_each_other() {
+        // A psql table whose data looks like a Markdown separator
+        let postgres = " id | note\n----+------\n  1 | |---|";
+        assert_eq!(detect_format(postgres), Format::PostgreSQL);
+
+        // A MySQL table whose data looks like a psql separator
+        let mysql = "+----+---------+\n| id | note    |\n+----+---------+\n| 1  | --+--   |\n+----+---------+\n---+---";
+        assert_eq!(detect_format(mysql), Format::MySQL);
+    }
+
     #[test]
     fn test_detect_tsv_with_pipes_in_data() {
         // TSV should be detected even if data contains pipe characters
@@ -156,6 +560,26 @@ mod tests {
         assert_eq!(detect_format(input), Format::TSV);
     }
 
+    #[test]
+    fn test_detect_markdown_after_long_preamble() {
+        let mut input: String = (1..=34).map(|i| format!("Banner line {}\n", i)).collect();
+        input.push_str("| id | name |\n|----|------|\n| 1 | Alice |");
+
+        assert_eq!(detect_format(&input), Format::CSV);
+        assert_eq!(detect_format_from_lines(&input, 40), Format::Markdown);
+    }
+
+    #[test]
+    fn test_sniff_delimiter() {
+        assert_eq!(sniff_delimiter("id,name\n1,Alice\n2,Bob"), b',');
+        assert_eq!(sniff_delimiter("id\tname\n1\tAlice"), b'\t');
+        assert_eq!(sniff_delimiter("a;b;c\n1;2;3"), b';');
+        // Commas inside quotes don't count, so the consistent delimiter wins
+        assert_eq!(sniff_delimiter("id|note\n1|\"a, b\"\n2|c"), b'|');
+        // No consistent candidate falls back to a comma
+        assert_eq!(sniff_delimiter("note\na|b\nc"), b',');
+    }
+
     // ReDoS vulnerability tests - ensure patterns complete quickly even with attack vectors
     #[test]
     fn test_postgres_sep_redos_protection() {
@@ -255,6 +679,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_detection_reads_bounded_prefix_of_huge_input() {
+        // A table just past the sample limit is out of reach
+        let padded = format!(
+            "{}| id |\n|----|\n| 1  |\n",
+            "\n".repeat(FORMAT_DETECTION_BYTE_LIMIT + 1)
+        );
+        // One line longer than the limit, with no newline to stop at and a
+        // two-byte character straddling the cut
+        let one_line = format!("a{}", "é".repeat(FORMAT_DETECTION_BYTE_LIMIT / 2));
+
+        assert_eq!(detect_format(&padded), Format::CSV);
+        assert_eq!(detect_format(&one_line), Format::CSV);
+        assert!(!is_plain_lines(&padded));
+        assert_eq!(sniff_delimiter(&one_line), b',');
+    }
+
+    #[test]
+    fn test_detection_sample_ends_on_line_boundary() {
+        let line = "a,b\n";
+        let input = line.repeat(FORMAT_DETECTION_BYTE_LIMIT / line.len() + 10);
+
+        let sample = detection_sample(&input);
+
+        assert!(sample.len() < FORMAT_DETECTION_BYTE_LIMIT);
+        assert!(sample.ends_with("a,b"));
+        assert_eq!(detection_sample("short"), "short");
+    }
+
     #[test]
     fn test_detect_mysql_with_leading_empty_lines() {
         let input = r#"
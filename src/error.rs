@@ -4,7 +4,10 @@ use std::fmt;
 ///
 /// This enum represents all possible errors that can occur during
 /// table operations, including parsing, validation, and I/O.
+///
+/// New variants may be added in minor releases, so matches need a `_` arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// Error during table parsing.
     ///
@@ -12,6 +15,28 @@ pub enum Error {
     /// The string contains details about what went wrong.
     ParseError(String),
 
+    /// Error during table parsing caused by an underlying error.
+    ///
+    /// Like [`Error::ParseError`], but keeps the original error (such as a
+    /// `csv::Error`) available through [`std::error::Error::source`].
+    ParseErrorWithSource {
+        /// Description of what went wrong, including the source's message
+        message: String,
+        /// The error that caused the failure
+        source: Box<dyn std::error::Error + Send + Sync + 'static>,
+    },
+
+    /// Error tied to a specific line of the input.
+    ///
+    /// Text parsers use this to point at the source line that broke the
+    /// table, such as a row with the wrong number of cells.
+    ParseErrorAt {
+        /// The 1-indexed line of the input
+        line: usize,
+        /// Description of what went wrong on that line
+        message: String,
+    },
+
     /// I/O error during reading or writing.
     ///
     /// This wraps standard I/O errors that occur when reading input or
@@ -43,12 +68,40 @@ pub enum Error {
         /// Actual number of columns found
         found: usize,
     },
+
+    /// A referenced column does not exist in the table.
+    ///
+    /// The string contains the column name (or pattern) that could not be
+    /// resolved against the table headers.
+    ColumnNotFound(String),
+
+    /// Two or more headers share the same name.
+    ///
+    /// Reported by strict validation, since name-based operations can't tell
+    /// the columns apart.
+    DuplicateHeader {
+        /// The repeated header name
+        name: String,
+    },
+
+    /// The requested output format has no writer.
+    ///
+    /// The message suggests the closest available format when the request
+    /// looks like a typo.
+    UnsupportedOutput {
+        /// The format name that was asked for
+        requested: String,
+        /// Names of the formats that can be written
+        available: Vec<String>,
+    },
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::ParseError(msg) => write!(f, "{}", msg),
+            Error::ParseErrorWithSource { message, .. } => write!(f, "{}", message),
+            Error::ParseErrorAt { line, message } => write!(f, "Line {}: {}", line, message),
             Error::IoError(err) => write!(f, "{}", err),
             Error::InvalidFormat(msg) => write!(f, "{}", msg),
             Error::InconsistentColumns {
@@ -62,14 +115,56 @@ impl fmt::Display for Error {
                     row, expected, found
                 )
             }
+            Error::ColumnNotFound(name) => write!(f, "Column not found: '{}'", name),
+            Error::DuplicateHeader { name } => write!(f, "Duplicate header: '{}'", name),
+            Error::UnsupportedOutput {
+                requested,
+                available,
+            } => {
+                write!(f, "Unsupported output format '{}'.", requested)?;
+                if let Some(suggestion) = closest_match(requested, available) {
+                    write!(f, " Did you mean '{}'?", suggestion)?;
+                }
+                write!(f, " Available formats: {}", available.join(", "))
+            }
         }
     }
 }
 
+/// Returns the candidate nearest to `input` by edit distance, if it's close
+/// enough to plausibly be a typo
+fn closest_match<'a>(input: &str, candidates: &'a [String]) -> Option<&'a str> {
+    let input = input.to_lowercase();
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(&input, candidate), candidate))
+        .filter(|(distance, candidate)| *distance <= 2 && *distance < candidate.len())
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.as_str())
+}
+
+/// Levenshtein distance between two strings, counted in chars
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Error::IoError(err) => Some(err),
+            Error::ParseErrorWithSource { source, .. } => Some(source.as_ref()),
             _ => None,
         }
     }
@@ -83,7 +178,16 @@ impl From<std::io::Error> for Error {
 
 impl From<csv::Error> for Error {
     fn from(err: csv::Error) -> Self {
-        Error::ParseError(err.to_string())
+        // Keep I/O failures (such as a closed pipe) distinguishable from bad data
+        match err.kind() {
+            csv::ErrorKind::Io(io_err) => {
+                Error::IoError(std::io::Error::new(io_err.kind(), io_err.to_string()))
+            }
+            _ => Error::ParseErrorWithSource {
+                message: err.to_string(),
+                source: Box::new(err),
+            },
+        }
     }
 }
 
@@ -106,3 +210,59 @@ impl From<csv::Error> for Error {
 /// }
 /// ```
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unsupported(requested: &str) -> String {
+        Error::UnsupportedOutput {
+            requested: requested.to_string(),
+            available: vec!["tsv".to_string(), "csv".to_string(), "json".to_string()],
+        }
+        .to_string()
+    }
+
+    #[test]
+    fn test_unsupported_output_suggests_closest() {
+        assert_eq!(
+            unsupported("cvs"),
+            "Unsupported output format 'cvs'. Did you mean 'csv'? Available formats: tsv, csv, json"
+        );
+        assert!(unsupported("JSN").contains("Did you mean 'json'?"));
+    }
+
+    #[test]
+    fn test_unsupported_output_without_close_match() {
+        let msg = unsupported("mysql");
+        assert!(!msg.contains("Did you mean"));
+        assert!(msg.contains("Available formats: tsv, csv, json"));
+    }
+
+    #[test]
+    fn test_csv_error_keeps_source() {
+        use std::error::Error as _;
+
+        let csv_err = csv::ReaderBuilder::new()
+            .from_reader("a,b\n1".as_bytes())
+            .records()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        let message = csv_err.to_string();
+
+        let err = Error::from(csv_err);
+
+        assert_eq!(err.to_string(), message);
+        let source = err.source().expect("csv error should be the source");
+        assert!(source.downcast_ref::<csv::Error>().is_some());
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("cvs", "csv"), 2);
+        assert_eq!(edit_distance("tsv", "tsv"), 0);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+}
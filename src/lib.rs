@@ -1,6 +1,8 @@
 pub mod detector;
 pub mod error;
 pub mod parser;
+pub mod query;
+pub mod types;
 pub mod writer;
 
 use error::Result;
@@ -104,6 +106,58 @@ impl Table {
         Ok(())
     }
 
+    /// Validates the table, additionally requiring unique header names.
+    ///
+    /// Performs the same checks as [`Table::validate`], then rejects tables
+    /// where two headers share a name, since selecting or joining by name
+    /// would be ambiguous.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Error::InconsistentColumns`] as `validate` does, or
+    /// [`error::Error::DuplicateHeader`] for the first repeated header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(vec!["id".to_string(), "id".to_string()], vec![]);
+    ///
+    /// assert!(table.validate().is_ok());
+    /// assert!(table.validate_strict().is_err());
+    /// ```
+    pub fn validate_strict(&self) -> Result<()> {
+        self.validate()?;
+
+        let mut seen = std::collections::HashSet::new();
+        for header in &self.headers {
+            if !seen.insert(header.as_str()) {
+                return Err(error::Error::DuplicateHeader {
+                    name: header.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if any header is the empty string.
+    ///
+    /// Such columns can't be selected by name and become empty JSON keys; a
+    /// leading delimiter on the header line is the usual cause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(vec!["".to_string(), "name".to_string()], vec![]);
+    /// assert!(table.has_empty_headers());
+    /// ```
+    pub fn has_empty_headers(&self) -> bool {
+        self.headers.iter().any(|header| header.is_empty())
+    }
+
     /// Creates a new table and validates it.
     ///
     /// This is the recommended way to create a table as it ensures data integrity
@@ -196,6 +250,42 @@ impl Table {
         self.headers.len()
     }
 
+    /// Returns an approximate number of bytes the table occupies in memory.
+    ///
+    /// This is the byte length of every header and cell, plus the size of a
+    /// `String` for each of them and of a `Vec<String>` for each row. Spare
+    /// capacity in those allocations isn't counted, so the real footprint
+    /// can be somewhat larger.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::mem::size_of;
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["id".to_string()],
+    ///     vec![vec!["42".to_string()]],
+    /// );
+    /// let overhead = 2 * size_of::<String>() + size_of::<Vec<String>>();
+    /// assert_eq!(table.estimated_bytes(), 4 + overhead);
+    /// ```
+    pub fn estimated_bytes(&self) -> usize {
+        let string_bytes = |values: &[String]| {
+            values
+                .iter()
+                .map(|value| value.len() + std::mem::size_of::<String>())
+                .sum::<usize>()
+        };
+
+        string_bytes(&self.headers)
+            + self
+                .rows
+                .iter()
+                .map(|row| string_bytes(row) + std::mem::size_of::<Vec<String>>())
+                .sum::<usize>()
+    }
+
     /// Returns a reference to the table headers.
     ///
     /// # Examples
@@ -234,6 +324,40 @@ impl Table {
         &self.rows
     }
 
+    /// Returns the first data row, or `None` if the table has no rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["id".to_string()],
+    ///     vec![vec!["1".to_string()], vec!["2".to_string()]],
+    /// );
+    /// assert_eq!(table.first_row(), Some(&["1".to_string()][..]));
+    /// ```
+    pub fn first_row(&self) -> Option<&[String]> {
+        self.rows.first().map(Vec::as_slice)
+    }
+
+    /// Returns the last data row, or `None` if the table has no rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["id".to_string()],
+    ///     vec![vec!["1".to_string()], vec!["2".to_string()]],
+    /// );
+    /// assert_eq!(table.last_row(), Some(&["2".to_string()][..]));
+    /// ```
+    pub fn last_row(&self) -> Option<&[String]> {
+        self.rows.last().map(Vec::as_slice)
+    }
+
     /// Consumes the table and returns the headers and rows.
     ///
     /// This is useful when you need ownership of the table's data.
@@ -255,164 +379,2582 @@ impl Table {
     pub fn into_parts(self) -> (Vec<String>, Vec<Vec<String>>) {
         (self.headers, self.rows)
     }
-}
 
-/// Supported table formats for parsing and auto-detection.
-///
-/// This enum represents the various table formats that can be parsed by the library.
-/// Formats can be auto-detected or explicitly specified.
-///
-/// # Examples
-///
-/// ```
-/// use table_extractor::Format;
-/// use std::str::FromStr;
-///
-/// // Parse format from string
-/// let format = Format::from_str("markdown").unwrap();
-/// assert_eq!(format, Format::Markdown);
-///
-/// // Case insensitive
-/// let format = Format::from_str("MySQL").unwrap();
-/// assert_eq!(format, Format::MySQL);
-///
-/// // Aliases are supported
-/// let format = Format::from_str("psql").unwrap();
-/// assert_eq!(format, Format::PostgreSQL);
-///
-/// // Display trait converts back to canonical string
-/// assert_eq!(format.to_string(), "postgresql");
-/// assert_eq!(Format::CSV.to_string(), "csv");
-///
-/// // Round-trip conversion works
-/// let original = Format::Markdown;
-/// let parsed = Format::from_str(&original.to_string()).unwrap();
-/// assert_eq!(original, parsed);
-/// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Format {
-    /// Markdown table format with pipe delimiters (e.g., `| col1 | col2 |`)
-    Markdown,
+    /// Returns the headers and rows together without consuming the table.
+    ///
+    /// This is the borrowing counterpart of [`Table::into_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["id".to_string(), "name".to_string()],
+    ///     vec![vec!["1".to_string(), "Alice".to_string()]],
+    /// );
+    ///
+    /// let (headers, rows) = table.as_parts();
+    /// assert_eq!(headers, &["id", "name"]);
+    /// assert_eq!(rows.len(), 1);
+    /// ```
+    pub fn as_parts(&self) -> (&[String], &[Vec<String>]) {
+        (&self.headers, &self.rows)
+    }
 
-    /// MySQL CLI output format with box-drawing characters (e.g., `+----+----+`)
-    MySQL,
+    /// Returns a new table with only the named columns, in the order given.
+    ///
+    /// Names are matched exactly (no wildcards). A name may be repeated to
+    /// duplicate a column, and an empty list keeps the rows but no columns.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Error::InvalidFormat`] naming the first requested
+    /// column that isn't a header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["id".to_string(), "name".to_string(), "email".to_string()],
+    ///     vec![vec!["1".to_string(), "Alice".to_string(), "a@x.com".to_string()]],
+    /// );
+    ///
+    /// let selected = table.select_columns(&["email", "id"]).unwrap();
+    /// assert_eq!(selected.headers(), &["email", "id"]);
+    /// assert_eq!(selected.rows()[0], vec!["a@x.com", "1"]);
+    /// ```
+    pub fn select_columns(&self, names: &[&str]) -> Result<Table> {
+        let indices = names
+            .iter()
+            .map(|name| {
+                self.headers
+                    .iter()
+                    .position(|header| header == name)
+                    .ok_or_else(|| {
+                        error::Error::InvalidFormat(format!("Column '{}' not found", name))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
 
-    /// PostgreSQL CLI output format with simple separators (e.g., `----+----`)
-    PostgreSQL,
+        let headers = indices.iter().map(|&i| self.headers[i].clone()).collect();
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| indices.iter().map(|&i| row[i].clone()).collect())
+            .collect();
 
-    /// Comma-separated values (CSV) format
-    CSV,
+        Ok(Table::new(headers, rows))
+    }
 
-    /// Tab-separated values (TSV) format
-    TSV,
-}
+    /// Returns a new table containing only the columns matching `patterns`.
+    ///
+    /// Each pattern is either a literal header name or a wildcard pattern
+    /// using `*` (any sequence of characters) and `?` (any single character).
+    /// Columns appear in the order the patterns are given; columns matched
+    /// by a wildcard keep their original relative order, and a wildcard
+    /// never re-selects a column already chosen by an earlier pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Error::ColumnNotFound`] if a literal name is not a
+    /// header or a wildcard pattern matches no columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["metric_a".to_string(), "id".to_string(), "metric_b".to_string()],
+    ///     vec![vec!["10".to_string(), "1".to_string(), "20".to_string()]],
+    /// );
+    ///
+    /// let selected = table.select_matching(&["id", "metric_*"]).unwrap();
+    /// assert_eq!(selected.headers(), &["id", "metric_a", "metric_b"]);
+    /// assert_eq!(selected.rows()[0], vec!["1", "10", "20"]);
+    /// ```
+    pub fn select_matching(&self, patterns: &[&str]) -> Result<Table> {
+        self.select_matching_with_positions(patterns)
+            .map(|(table, _)| table)
+    }
 
-impl FromStr for Format {
-    type Err = String;
+    /// Like [`Table::select_matching`], but also returns where each literal
+    /// pattern's column landed in the result.
+    ///
+    /// The second value has one entry per pattern: the output column index
+    /// for a literal name, or `None` for a wildcard, which may match any
+    /// number of columns.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Table::select_matching`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["metric_a".to_string(), "id".to_string(), "metric_b".to_string()],
+    ///     vec![vec!["10".to_string(), "1".to_string(), "20".to_string()]],
+    /// );
+    ///
+    /// let (selected, positions) = table
+    ///     .select_matching_with_positions(&["metric_*", "id"])
+    ///     .unwrap();
+    /// assert_eq!(selected.headers(), &["metric_a", "metric_b", "id"]);
+    /// assert_eq!(positions, vec![None, Some(2)]);
+    /// ```
+    pub fn select_matching_with_positions(
+        &self,
+        patterns: &[&str],
+    ) -> Result<(Table, Vec<Option<usize>>)> {
+        let mut indices = Vec::with_capacity(patterns.len());
+        let mut positions = Vec::with_capacity(patterns.len());
 
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "markdown" | "md" => Ok(Format::Markdown),
-            "mysql" => Ok(Format::MySQL),
-            "postgres" | "postgresql" | "psql" => Ok(Format::PostgreSQL),
-            "csv" => Ok(Format::CSV),
-            "tsv" => Ok(Format::TSV),
-            _ => Err(format!(
-                "Invalid format: '{}'. Valid formats: markdown, mysql, postgres, csv, tsv",
-                s
-            )),
+        for pattern in patterns {
+            if is_wildcard_pattern(pattern) {
+                let matches: Vec<usize> = self
+                    .headers
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, header)| wildcard_match(pattern, header))
+                    .map(|(idx, _)| idx)
+                    .collect();
+
+                if matches.is_empty() {
+                    return Err(error::Error::ColumnNotFound(pattern.to_string()));
+                }
+
+                for idx in matches {
+                    if !indices.contains(&idx) {
+                        indices.push(idx);
+                    }
+                }
+                positions.push(None);
+            } else {
+                positions.push(Some(indices.len()));
+                indices.push(self.find_column(pattern)?);
+            }
         }
+
+        let headers = indices.iter().map(|&i| self.headers[i].clone()).collect();
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| indices.iter().map(|&i| row[i].clone()).collect())
+            .collect();
+
+        Ok((Table::new(headers, rows), positions))
     }
-}
 
-impl std::fmt::Display for Format {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let name = match self {
-            Format::Markdown => "markdown",
-            Format::MySQL => "mysql",
-            Format::PostgreSQL => "postgresql",
-            Format::CSV => "csv",
-            Format::TSV => "tsv",
-        };
-        write!(f, "{}", name)
+    /// Returns `true` if both tables have the same headers, ignoring ASCII
+    /// and Unicode case differences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let a = Table::new(vec!["ID".to_string(), "Name".to_string()], vec![]);
+    /// let b = Table::new(vec!["id".to_string(), "name".to_string()], vec![]);
+    ///
+    /// assert_ne!(a.headers(), b.headers());
+    /// assert!(a.headers_eq_ignoring_case(&b));
+    /// ```
+    pub fn headers_eq_ignoring_case(&self, other: &Table) -> bool {
+        self.headers.len() == other.headers.len()
+            && self
+                .headers
+                .iter()
+                .zip(&other.headers)
+                .all(|(a, b)| a.to_lowercase() == b.to_lowercase())
     }
-}
 
-/// Trait for parsing table data from various input formats.
-///
-/// Implement this trait to add support for new table formats.
-/// All parsers should validate column consistency by using [`Table::new_validated`].
-///
-/// # Examples
-///
-/// ```
-/// use table_extractor::{Parser, Table, error::Result};
-///
-/// struct CustomParser;
-///
-/// impl Parser for CustomParser {
-///     fn parse(&self, input: &str) -> Result<Table> {
-///         // Parse input into headers and rows
-///         let headers = vec!["col1".to_string(), "col2".to_string()];
-///         let rows = vec![vec!["val1".to_string(), "val2".to_string()]];
-///
-///         // Use new_validated to ensure data integrity
-///         Table::new_validated(headers, rows)
-///     }
-/// }
-/// ```
-pub trait Parser {
-    /// Parses the input string into a table.
+    /// Returns `true` if every name in `required` is a header. Extra columns
+    /// are allowed, and the order doesn't matter.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// Returns an error if the input cannot be parsed or if the resulting
-    /// table fails validation (inconsistent columns, too many columns, etc.).
-    fn parse(&self, input: &str) -> Result<Table>;
-}
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["id".to_string(), "email".to_string(), "name".to_string()],
+    ///     vec![],
+    /// );
+    ///
+    /// assert!(table.has_columns(&["email", "id"]));
+    /// assert!(!table.has_columns(&["id", "phone"]));
+    /// ```
+    pub fn has_columns(&self, required: &[&str]) -> bool {
+        required
+            .iter()
+            .all(|name| self.headers.iter().any(|h| h == name))
+    }
 
-/// Trait for writing table data to various output formats.
-///
-/// Implement this trait to add support for new output formats.
-///
-/// # Examples
-///
-/// ```
-/// use table_extractor::{Writer, Table, error::Result};
-/// use std::io::Write as IoWrite;
-///
-/// struct CustomWriter;
-///
-/// impl Writer for CustomWriter {
-///     fn write(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
-///         // Write headers
-///         writeln!(output, "{}", table.headers().join(","))?;
-///
-///         // Write rows
-///         for row in table.rows() {
-///             writeln!(output, "{}", row.join(","))?;
-///         }
-///
-///         Ok(())
-///     }
-/// }
-/// ```
-pub trait Writer {
-    /// Writes the table to the provided output stream.
+    /// Returns the names in `required` that aren't headers, in the order given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(vec!["id".to_string(), "name".to_string()], vec![]);
+    ///
+    /// assert_eq!(table.missing_columns(&["id", "email", "phone"]), vec!["email", "phone"]);
+    /// assert!(table.missing_columns(&["name"]).is_empty());
+    /// ```
+    pub fn missing_columns(&self, required: &[&str]) -> Vec<String> {
+        required
+            .iter()
+            .filter(|name| !self.headers.iter().any(|h| h == *name))
+            .map(|name| name.to_string())
+            .collect()
+    }
+
+    /// Concatenates tables vertically, appending all rows under one header.
     ///
     /// # Errors
     ///
-    /// Returns an error if writing fails or if the table data is invalid
-    /// for the output format (e.g., delimiter conflicts).
-    fn write(&self, table: &Table, output: &mut dyn Write) -> Result<()>;
-}
+    /// Returns [`error::Error::InvalidFormat`] if any table's headers differ
+    /// from the first table's headers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let a = Table::new(vec!["id".to_string()], vec![vec!["1".to_string()]]);
+    /// let b = Table::new(vec!["id".to_string()], vec![vec!["2".to_string()]]);
+    ///
+    /// let merged = Table::concat(vec![a, b]).unwrap();
+    /// assert_eq!(merged.rows(), &[vec!["1"], vec!["2"]]);
+    /// ```
+    pub fn concat(tables: Vec<Table>) -> Result<Table> {
+        Self::concat_with(tables, |a, b| a.headers == b.headers)
+    }
+
+    /// Concatenates tables vertically, treating headers that differ only by
+    /// case as equal. The result keeps the first table's header casing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Error::InvalidFormat`] if any table's headers differ
+    /// from the first table's headers by more than case.
+    pub fn concat_ignoring_header_case(tables: Vec<Table>) -> Result<Table> {
+        Self::concat_with(tables, Table::headers_eq_ignoring_case)
+    }
+
+    fn concat_with<F: Fn(&Table, &Table) -> bool>(
+        tables: Vec<Table>,
+        headers_match: F,
+    ) -> Result<Table> {
+        let mut tables = tables.into_iter();
+        let Some(mut merged) = tables.next() else {
+            return Ok(Table::new(vec![], vec![]));
+        };
+
+        for (idx, table) in tables.enumerate() {
+            if !headers_match(&merged, &table) {
+                return Err(error::Error::InvalidFormat(format!(
+                    "Header mismatch in table {}: expected [{}], found [{}]",
+                    idx + 2,
+                    merged.headers.join(", "),
+                    table.headers.join(", ")
+                )));
+            }
+            merged.rows.extend(table.rows);
+        }
+
+        Ok(merged)
+    }
+
+    /// Appends `other`'s rows to this table in place.
+    ///
+    /// This is the in-place counterpart of [`Table::concat`]; `other` is
+    /// consumed, so its rows are moved rather than cloned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Error::InvalidFormat`] if the headers differ. This
+    /// table is left unchanged in that case.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if any row's length differs from the header
+    /// count afterwards, which includes a table that was already ragged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut all = Table::new(vec!["id".to_string()], vec![vec!["1".to_string()]]);
+    /// let batch = Table::new(vec!["id".to_string()], vec![vec!["2".to_string()]]);
+    ///
+    /// all.append_table(batch).unwrap();
+    /// assert_eq!(all.rows(), &[vec!["1"], vec!["2"]]);
+    /// ```
+    pub fn append_table(&mut self, other: Table) -> Result<()> {
+        if self.headers != other.headers {
+            return Err(error::Error::InvalidFormat(format!(
+                "Header mismatch: expected [{}], found [{}]",
+                self.headers.join(", "),
+                other.headers.join(", ")
+            )));
+        }
+
+        self.rows.extend(other.rows);
+        self.debug_assert_rectangular();
+        Ok(())
+    }
+
+    /// Appends `other`'s columns to this table's, pairing rows by position.
+    ///
+    /// Headers from `other` that collide with an existing header get a numeric
+    /// suffix (`name_2`, `name_3`, ...) so every column stays addressable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Error::InvalidFormat`] if the tables have different
+    /// row counts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let left = Table::new(
+    ///     vec!["id".to_string(), "name".to_string()],
+    ///     vec![vec!["1".to_string(), "Alice".to_string()]],
+    /// );
+    /// let right = Table::new(vec!["name".to_string()], vec![vec!["Smith".to_string()]]);
+    ///
+    /// let pasted = left.concat_columns(&right).unwrap();
+    /// assert_eq!(pasted.headers(), &["id", "name", "name_2"]);
+    /// assert_eq!(pasted.rows()[0], vec!["1", "Alice", "Smith"]);
+    /// ```
+    pub fn concat_columns(&self, other: &Table) -> Result<Table> {
+        if self.rows.len() != other.rows.len() {
+            return Err(error::Error::InvalidFormat(format!(
+                "Row count mismatch: left table has {} rows, right table has {}",
+                self.rows.len(),
+                other.rows.len()
+            )));
+        }
+
+        let mut headers = self.headers.clone();
+        for header in &other.headers {
+            let name = unique_header(&headers, header);
+            headers.push(name);
+        }
+
+        let rows = self
+            .rows
+            .iter()
+            .zip(&other.rows)
+            .map(|(left, right)| left.iter().chain(right).cloned().collect())
+            .collect();
+
+        Ok(Table::new(headers, rows))
+    }
+
+    /// Renames repeated headers with a numeric suffix (`name_2`, `name_3`, ...)
+    /// so every column is addressable. The first occurrence keeps its name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["id".to_string(), "id".to_string(), "name".to_string()],
+    ///     vec![],
+    /// );
+    ///
+    /// table.dedup_headers();
+    /// assert_eq!(table.headers(), &["id", "id_2", "name"]);
+    /// ```
+    pub fn dedup_headers(&mut self) {
+        self.headers = deduped_headers(&self.headers);
+    }
+
+    /// Removes a leading `table.` qualifier from each header, as produced by
+    /// SQL joins (`users.id` becomes `id`). Headers without a dot are kept.
+    ///
+    /// Names that collide after stripping are made unique with
+    /// [`Table::dedup_headers`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["users.id".to_string(), "users.name".to_string(), "orders.id".to_string()],
+    ///     vec![],
+    /// );
+    ///
+    /// table.strip_header_prefixes();
+    /// assert_eq!(table.headers(), &["id", "name", "id_2"]);
+    /// ```
+    pub fn strip_header_prefixes(&mut self) {
+        for header in &mut self.headers {
+            if let Some((_, name)) = header.split_once('.') {
+                *header = name.to_string();
+            }
+        }
+        self.dedup_headers();
+    }
+
+    /// Normalizes every header and cell to Unicode Normalization Form C.
+    ///
+    /// Text from different sources may spell the same character composed
+    /// (`é`) or decomposed (`e` + combining accent); after normalization both
+    /// are byte-identical. Applying it more than once has no further effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["name".to_string()],
+    ///     vec![vec!["cafe\u{301}".to_string()]],
+    /// );
+    ///
+    /// table.normalize_unicode();
+    /// assert_eq!(table.rows()[0][0], "caf\u{e9}");
+    /// ```
+    #[cfg(feature = "unicode")]
+    pub fn normalize_unicode(&mut self) {
+        use unicode_normalization::UnicodeNormalization;
+
+        for cell in self
+            .headers
+            .iter_mut()
+            .chain(self.rows.iter_mut().flatten())
+        {
+            *cell = cell.nfc().collect();
+        }
+    }
+
+    /// Trims leading and trailing whitespace from every header and cell.
+    ///
+    /// With `collapse_internal`, runs of whitespace inside a value are also
+    /// replaced by a single space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec![" name ".to_string()],
+    ///     vec![vec!["  Ada   Lovelace ".to_string()]],
+    /// );
+    ///
+    /// table.trim_cells(true);
+    /// assert_eq!(table.headers(), &["name"]);
+    /// assert_eq!(table.rows()[0][0], "Ada Lovelace");
+    /// ```
+    pub fn trim_cells(&mut self, collapse_internal: bool) {
+        for cell in self
+            .headers
+            .iter_mut()
+            .chain(self.rows.iter_mut().flatten())
+        {
+            *cell = if collapse_internal {
+                cell.split_whitespace().collect::<Vec<_>>().join(" ")
+            } else {
+                cell.trim().to_string()
+            };
+        }
+    }
+
+    /// Renames columns given `(old, new)` pairs. Data rows are untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Error::ColumnNotFound`] if any old name doesn't match
+    /// a header. The table is left unchanged in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["id".to_string(), "name".to_string()],
+    ///     vec![vec!["1".to_string(), "Alice".to_string()]],
+    /// );
+    ///
+    /// table.rename_columns(&[("name", "full_name")]).unwrap();
+    /// assert_eq!(table.headers(), &["id", "full_name"]);
+    /// ```
+    pub fn rename_columns(&mut self, renames: &[(&str, &str)]) -> Result<()> {
+        let indices = renames
+            .iter()
+            .map(|(old, _)| self.find_column(old))
+            .collect::<Result<Vec<_>>>()?;
+
+        for (idx, (_, new)) in indices.into_iter().zip(renames) {
+            self.headers[idx] = new.to_string();
+        }
+
+        Ok(())
+    }
+
+    /// Returns each data row with its cells joined by `delimiter`.
+    ///
+    /// This is a convenience for simple scripts; cells are not escaped, so the
+    /// result is ambiguous if any cell contains the delimiter or a newline.
+    /// Use a [`Writer`] when the data isn't known to be safe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["id".to_string(), "name".to_string()],
+    ///     vec![vec!["1".to_string(), "Alice".to_string()]],
+    /// );
+    ///
+    /// assert_eq!(table.rows_as_strings('|'), vec!["1|Alice"]);
+    /// ```
+    pub fn rows_as_strings(&self, delimiter: char) -> Vec<String> {
+        let delimiter = delimiter.to_string();
+        self.rows.iter().map(|row| row.join(&delimiter)).collect()
+    }
+
+    /// Rewrites each header in place using `f`, which receives the column
+    /// index and the current header. Data rows are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["id".to_string(), "name".to_string()],
+    ///     vec![vec!["1".to_string(), "Alice".to_string()]],
+    /// );
+    ///
+    /// table.transform_headers(|_, header| format!("col_{}", header));
+    /// assert_eq!(table.headers(), &["col_id", "col_name"]);
+    /// ```
+    pub fn transform_headers<F: Fn(usize, &str) -> String>(&mut self, f: F) {
+        for (idx, header) in self.headers.iter_mut().enumerate() {
+            *header = f(idx, header);
+        }
+    }
+
+    /// Rewrites each header in place using `f`, leaving rows untouched.
+    ///
+    /// Sanitizing can map distinct headers to the same name; follow up with
+    /// [`Table::dedup_headers`] when they need to stay unique.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["First Name".to_string(), "first name".to_string()],
+    ///     vec![],
+    /// );
+    ///
+    /// table.rename_headers(|header| header.to_lowercase().replace(' ', "_"));
+    /// table.dedup_headers();
+    /// assert_eq!(table.headers(), &["first_name", "first_name_2"]);
+    /// ```
+    pub fn rename_headers<F: Fn(&str) -> String>(&mut self, f: F) {
+        self.transform_headers(|_, header| f(header));
+    }
+
+    /// Replaces empty cells in the named columns with the closest non-empty
+    /// value above them, as spreadsheets do when exporting merged cells.
+    ///
+    /// Cells containing only whitespace count as empty. Empty cells before the
+    /// first value in a column stay empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Error::ColumnNotFound`] if any name doesn't match a
+    /// header. The table is left unchanged in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["team".to_string(), "name".to_string()],
+    ///     vec![
+    ///         vec!["Red".to_string(), "Alice".to_string()],
+    ///         vec!["".to_string(), "Bob".to_string()],
+    ///     ],
+    /// );
+    ///
+    /// table.fill_down(&["team"]).unwrap();
+    /// assert_eq!(table.rows()[1], vec!["Red", "Bob"]);
+    /// ```
+    pub fn fill_down(&mut self, cols: &[&str]) -> Result<()> {
+        let indices = cols
+            .iter()
+            .map(|name| self.find_column(name))
+            .collect::<Result<Vec<_>>>()?;
+
+        for col in indices {
+            let mut last: Option<String> = None;
+            for row in &mut self.rows {
+                if row[col].trim().is_empty() {
+                    if let Some(value) = &last {
+                        row[col] = value.clone();
+                    }
+                } else {
+                    last = Some(row[col].clone());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replaces every cell in the named column with `f` applied to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Error::ColumnNotFound`] if no header matches `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["id".to_string(), "status".to_string()],
+    ///     vec![
+    ///         vec!["1".to_string(), "active".to_string()],
+    ///         vec!["2".to_string(), "closed".to_string()],
+    ///     ],
+    /// );
+    ///
+    /// table.map_column("status", |value| value.to_uppercase()).unwrap();
+    /// assert_eq!(table.rows()[0], vec!["1", "ACTIVE"]);
+    /// assert_eq!(table.rows()[1], vec!["2", "CLOSED"]);
+    /// ```
+    pub fn map_column<F: Fn(&str) -> String>(&mut self, name: &str, f: F) -> Result<()> {
+        let col = self.find_column(name)?;
+        for row in &mut self.rows {
+            row[col] = f(&row[col]);
+        }
+        Ok(())
+    }
+
+    /// Splits the named column's cells on `sep`, emitting one row per part
+    /// with the other columns repeated.
+    ///
+    /// An empty cell yields a single row with an empty value. An empty `sep`
+    /// leaves the table unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Error::ColumnNotFound`] if no header matches `name`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if any row's length differs from the header
+    /// count afterwards, which includes a table that was already ragged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["id".to_string(), "tags".to_string()],
+    ///     vec![vec!["1".to_string(), "a;b".to_string()]],
+    /// );
+    ///
+    /// table.explode("tags", ";").unwrap();
+    /// assert_eq!(table.rows(), &[vec!["1", "a"], vec!["1", "b"]]);
+    /// ```
+    pub fn explode(&mut self, name: &str, sep: &str) -> Result<()> {
+        let col = self.find_column(name)?;
+        if sep.is_empty() {
+            return Ok(());
+        }
+
+        let mut rows = Vec::with_capacity(self.rows.len());
+        for row in std::mem::take(&mut self.rows) {
+            if !row[col].contains(sep) {
+                rows.push(row);
+                continue;
+            }
+            for part in row[col].split(sep) {
+                let mut exploded = row.clone();
+                exploded[col] = part.to_string();
+                rows.push(exploded);
+            }
+        }
+        self.rows = rows;
+        self.debug_assert_rectangular();
+
+        Ok(())
+    }
+
+    /// Renders the table with the given writer and returns the output.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error reported by the writer, or
+    /// [`error::Error::ParseError`] if it produces invalid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::writer::TsvWriter;
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(vec!["id".to_string()], vec![vec!["1".to_string()]]);
+    ///
+    /// assert_eq!(table.write_to_string(&TsvWriter::default()).unwrap(), "id\n1\n");
+    /// ```
+    pub fn write_to_string(&self, writer: &dyn Writer) -> Result<String> {
+        let mut output = Vec::new();
+        writer.write(self, &mut output)?;
+        String::from_utf8(output).map_err(|e| error::Error::ParseError(e.to_string()))
+    }
+
+    /// Renders the table as an HTML `<table>` element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(vec!["id".to_string()], vec![vec!["1".to_string()]]);
+    ///
+    /// let html = table.to_html_string();
+    /// assert!(html.starts_with("<table>"));
+    /// assert!(html.contains("<td>1</td>"));
+    /// ```
+    pub fn to_html_string(&self) -> String {
+        self.write_to_string(&writer::HtmlWriter)
+            .expect("HTML rendering into memory is infallible")
+    }
+
+    /// Renders the table as a GitHub-flavored Markdown table.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Error::InvalidFormat`] if a header or cell contains a
+    /// line break, which a Markdown table row can't hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(vec!["id".to_string()], vec![vec!["1".to_string()]]);
+    ///
+    /// assert_eq!(table.to_markdown_string().unwrap(), "| id |\n|---|\n| 1 |\n");
+    /// ```
+    pub fn to_markdown_string(&self) -> Result<String> {
+        self.write_to_string(&writer::MarkdownWriter)
+    }
+
+    /// Counts how often each distinct value occurs in the named column.
+    ///
+    /// Results are ordered by descending count, with ties broken by value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Error::ColumnNotFound`] if no header matches `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["color".to_string()],
+    ///     vec![
+    ///         vec!["red".to_string()],
+    ///         vec!["blue".to_string()],
+    ///         vec!["red".to_string()],
+    ///     ],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     table.value_counts("color").unwrap(),
+    ///     vec![("red".to_string(), 2), ("blue".to_string(), 1)]
+    /// );
+    /// ```
+    pub fn value_counts(&self, name: &str) -> Result<Vec<(String, usize)>> {
+        let col = self.find_column(name)?;
+
+        let mut counts: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+        for row in &self.rows {
+            *counts.entry(row[col].as_str()).or_insert(0) += 1;
+        }
+
+        let mut counts: Vec<(String, usize)> = counts
+            .into_iter()
+            .map(|(value, count)| (value.to_string(), count))
+            .collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(counts)
+    }
+
+    /// Returns a new table with the data rows in `start..end` (0-based,
+    /// end-exclusive). Both bounds are clamped to the row count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["n".to_string()],
+    ///     (1..=5).map(|n| vec![n.to_string()]).collect(),
+    /// );
+    ///
+    /// let sliced = table.slice_rows(1, 3);
+    /// assert_eq!(sliced.rows(), &[vec!["2"], vec!["3"]]);
+    /// assert!(table.slice_rows(4, 100).rows().len() == 1);
+    /// ```
+    pub fn slice_rows(&self, start: usize, end: usize) -> Table {
+        let end = end.min(self.rows.len());
+        let start = start.min(end);
+        Table::new(self.headers.clone(), self.rows[start..end].to_vec())
+    }
+
+    /// Removes and returns the data row at `index` (0-based), shifting later
+    /// rows up, or returns `None` if there is no such row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["n".to_string()],
+    ///     (1..=3).map(|n| vec![n.to_string()]).collect(),
+    /// );
+    ///
+    /// assert_eq!(table.remove_row(0), Some(vec!["1".to_string()]));
+    /// assert_eq!(table.remove_row(5), None);
+    /// assert_eq!(table.rows(), &[vec!["2"], vec!["3"]]);
+    /// ```
+    pub fn remove_row(&mut self, index: usize) -> Option<Vec<String>> {
+        (index < self.rows.len()).then(|| self.rows.remove(index))
+    }
+
+    /// Keeps only the first `n` data rows. Does nothing if the table has
+    /// `n` rows or fewer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["n".to_string()],
+    ///     (1..=5).map(|n| vec![n.to_string()]).collect(),
+    /// );
+    ///
+    /// table.truncate_rows(2);
+    /// assert_eq!(table.rows(), &[vec!["1"], vec!["2"]]);
+    /// ```
+    pub fn truncate_rows(&mut self, n: usize) {
+        self.rows.truncate(n);
+    }
+
+    /// Sorts the data rows with a custom comparator. Headers stay in place.
+    ///
+    /// The sort is stable: rows that compare equal keep their relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["name".to_string(), "age".to_string()],
+    ///     vec![
+    ///         vec!["Alice".to_string(), "30".to_string()],
+    ///         vec!["Bob".to_string(), "9".to_string()],
+    ///     ],
+    /// );
+    ///
+    /// let age = |row: &[String]| row[1].parse::<u32>().unwrap_or(0);
+    /// table.sort_by(|a, b| age(a).cmp(&age(b)));
+    /// assert_eq!(table.rows()[0][0], "Bob");
+    /// ```
+    pub fn sort_by<F: Fn(&[String], &[String]) -> std::cmp::Ordering>(&mut self, cmp: F) {
+        self.rows.sort_by(|a, b| cmp(a, b));
+    }
+
+    /// Returns a new table with the same headers and only the rows for which
+    /// `pred` returns `true`. Only the kept rows are cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["name".to_string(), "status".to_string()],
+    ///     vec![
+    ///         vec!["Alice".to_string(), "active".to_string()],
+    ///         vec!["Bob".to_string(), "closed".to_string()],
+    ///     ],
+    /// );
+    ///
+    /// let active = table.filter_rows(|row| row[1] == "active");
+    /// assert_eq!(active.rows(), &[vec!["Alice", "active"]]);
+    /// ```
+    pub fn filter_rows<F: Fn(&[String]) -> bool>(&self, pred: F) -> Table {
+        let rows = self.rows.iter().filter(|row| pred(row)).cloned().collect();
+        Table::new(self.headers.clone(), rows)
+    }
+
+    /// Iterates over the data rows in consecutive slices of up to `size` rows;
+    /// the last slice holds whatever remains.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["n".to_string()],
+    ///     (1..=5).map(|n| vec![n.to_string()]).collect(),
+    /// );
+    ///
+    /// let mut batches = Vec::new();
+    /// for chunk in table.row_chunks(2) {
+    ///     let values: Vec<&str> = chunk.iter().map(|row| row[0].as_str()).collect();
+    ///     batches.push(values.join("+"));
+    /// }
+    /// assert_eq!(batches, vec!["1+2", "3+4", "5"]);
+    /// ```
+    pub fn row_chunks(&self, size: usize) -> impl Iterator<Item = &[Vec<String>]> {
+        self.rows.chunks(size)
+    }
+
+    /// Prepends a column of 1-based row numbers under the given header.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if any row's length differs from the header
+    /// count afterwards, which includes a table that was already ragged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["name".to_string()],
+    ///     vec![vec!["Alice".to_string()], vec!["Bob".to_string()]],
+    /// );
+    ///
+    /// table.add_row_numbers("#");
+    /// assert_eq!(table.headers(), &["#", "name"]);
+    /// assert_eq!(table.rows()[1], vec!["2", "Bob"]);
+    /// ```
+    pub fn add_row_numbers(&mut self, header: &str) {
+        self.headers.insert(0, header.to_string());
+        for (idx, row) in self.rows.iter_mut().enumerate() {
+            row.insert(0, (idx + 1).to_string());
+        }
+        self.debug_assert_rectangular();
+    }
+
+    /// Panics in debug builds if a mutation left a row whose length differs
+    /// from the header count.
+    #[cfg(debug_assertions)]
+    fn debug_assert_rectangular(&self) {
+        if let Err(e) = self.validate() {
+            panic!("Table mutation broke the column invariant: {}", e);
+        }
+    }
+
+    #[cfg(not(debug_assertions))]
+    fn debug_assert_rectangular(&self) {}
+
+    /// Renders the table's exact structure for troubleshooting.
+    ///
+    /// Every cell is wrapped in `[` `]` so leading/trailing whitespace and
+    /// empty cells are visible, and column widths (in characters) are listed.
+    /// The output is meant for bug reports and is not round-trippable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["id".to_string(), "name".to_string()],
+    ///     vec![vec!["1".to_string(), " Alice".to_string()]],
+    /// );
+    ///
+    /// let dump = table.to_debug_string();
+    /// assert!(dump.contains("headers: [id] [name]"));
+    /// assert!(dump.contains("row 1: [1] [ Alice]"));
+    /// assert!(dump.contains("widths: 2, 6"));
+    /// ```
+    pub fn to_debug_string(&self) -> String {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let bracketed = |cells: &[String]| {
+            cells
+                .iter()
+                .map(|cell| format!("[{}]", cell))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        let mut out = format!(
+            "columns: {}\nrows: {}\nwidths: {}\nheaders: {}\n",
+            self.headers.len(),
+            self.rows.len(),
+            widths
+                .iter()
+                .map(|w| w.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            bracketed(&self.headers)
+        );
+        for (idx, row) in self.rows.iter().enumerate() {
+            out.push_str(&format!("row {}: {}\n", idx + 1, bracketed(row)));
+        }
+        out
+    }
+
+    /// Returns the number of empty cells in each column, in column order.
+    ///
+    /// Cells that are empty or contain only whitespace count as null.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["id".to_string(), "email".to_string()],
+    ///     vec![
+    ///         vec!["1".to_string(), "".to_string()],
+    ///         vec!["2".to_string(), "b@c.d".to_string()],
+    ///     ],
+    /// );
+    /// assert_eq!(table.null_counts(), vec![0, 1]);
+    /// ```
+    pub fn null_counts(&self) -> Vec<usize> {
+        let mut counts = vec![0; self.headers.len()];
+        for row in &self.rows {
+            for (count, cell) in counts.iter_mut().zip(row) {
+                if cell.trim().is_empty() {
+                    *count += 1;
+                }
+            }
+        }
+        counts
+    }
+
+    /// Returns an owned copy of the values in the named column, in row order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Error::ColumnNotFound`] if no header matches `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["id".to_string(), "name".to_string()],
+    ///     vec![
+    ///         vec!["1".to_string(), "Alice".to_string()],
+    ///         vec!["2".to_string(), "Bob".to_string()],
+    ///         vec!["1".to_string(), "Alice".to_string()],
+    ///     ],
+    /// );
+    ///
+    /// let ids: HashSet<String> = table.column("id").unwrap().into_iter().collect();
+    /// assert_eq!(ids.len(), 2);
+    /// assert!(ids.contains("2"));
+    /// ```
+    pub fn column(&self, name: &str) -> Result<Vec<String>> {
+        let col = self.find_column(name)?;
+        Ok(self.rows.iter().map(|row| row[col].clone()).collect())
+    }
+
+    /// Returns the index of the first column whose header equals `name`.
+    pub(crate) fn find_column(&self, name: &str) -> Result<usize> {
+        self.headers
+            .iter()
+            .position(|header| header == name)
+            .ok_or_else(|| error::Error::ColumnNotFound(name.to_string()))
+    }
+
+    /// Returns a new table with only the first `n` columns.
+    ///
+    /// If `n` exceeds the column count, the whole table is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["id".to_string(), "name".to_string(), "email".to_string()],
+    ///     vec![vec!["1".to_string(), "Alice".to_string(), "a@b.c".to_string()]],
+    /// );
+    ///
+    /// let preview = table.take_columns(2);
+    /// assert_eq!(preview.headers(), &["id", "name"]);
+    /// assert_eq!(preview.rows()[0], vec!["1", "Alice"]);
+    ///
+    /// assert_eq!(table.take_columns(10), table);
+    /// ```
+    pub fn take_columns(&self, n: usize) -> Table {
+        let n = n.min(self.headers.len());
+        let headers = self.headers[..n].to_vec();
+        let rows = self.rows.iter().map(|row| row[..n].to_vec()).collect();
+        Table::new(headers, rows)
+    }
+
+    /// Returns a new table keeping only the columns for which `f` returns `true`.
+    ///
+    /// The predicate receives each column's header and its values, in row order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["id".to_string(), "notes".to_string()],
+    ///     vec![
+    ///         vec!["1".to_string(), "".to_string()],
+    ///         vec!["2".to_string(), "".to_string()],
+    ///     ],
+    /// );
+    ///
+    /// // Drop columns that are entirely empty
+    /// let kept = table.retain_columns_where(|_, values| values.iter().any(|v| !v.is_empty()));
+    /// assert_eq!(kept.headers(), &["id"]);
+    /// assert_eq!(kept.rows()[1], vec!["2"]);
+    /// ```
+    pub fn retain_columns_where<F: Fn(&str, &[String]) -> bool>(&self, f: F) -> Table {
+        let keep: Vec<usize> = (0..self.headers.len())
+            .filter(|&col| {
+                let values: Vec<String> = self.rows.iter().map(|row| row[col].clone()).collect();
+                f(&self.headers[col], &values)
+            })
+            .collect();
+
+        let headers = keep.iter().map(|&i| self.headers[i].clone()).collect();
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| keep.iter().map(|&i| row[i].clone()).collect())
+            .collect();
+
+        Table::new(headers, rows)
+    }
+
+    /// Returns a new table with rows and columns swapped.
+    ///
+    /// The original headers become the first column, under a `column`
+    /// header. Each original row becomes a column named `row_N` (1-indexed).
+    ///
+    /// Edge cases are well-defined:
+    /// - A table with no columns transposes to an empty table.
+    /// - A headers-only table transposes to a single `column` column
+    ///   listing the header names.
+    /// - A single-row table transposes to a two-column table.
+    ///
+    /// Column alignment (such as Markdown `:---:` markers) is not carried:
+    /// tables hold no alignment metadata, so the transposed columns always
+    /// start from the default alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["id".to_string(), "name".to_string()],
+    ///     vec![
+    ///         vec!["1".to_string(), "Alice".to_string()],
+    ///         vec!["2".to_string(), "Bob".to_string()],
+    ///     ],
+    /// );
+    ///
+    /// let transposed = table.transpose();
+    /// assert_eq!(transposed.headers(), &["column", "row_1", "row_2"]);
+    /// assert_eq!(transposed.rows()[0], vec!["id", "1", "2"]);
+    /// assert_eq!(transposed.rows()[1], vec!["name", "Alice", "Bob"]);
+    /// ```
+    pub fn transpose(&self) -> Table {
+        if self.headers.is_empty() {
+            return Table::new(vec![], vec![]);
+        }
+
+        let mut headers = Vec::with_capacity(self.rows.len() + 1);
+        headers.push("column".to_string());
+        headers.extend((1..=self.rows.len()).map(|i| format!("row_{}", i)));
+
+        let rows = self
+            .headers
+            .iter()
+            .enumerate()
+            .map(|(col, header)| {
+                let mut new_row = Vec::with_capacity(self.rows.len() + 1);
+                new_row.push(header.clone());
+                new_row.extend(self.rows.iter().map(|row| row[col].clone()));
+                new_row
+            })
+            .collect();
+
+        Table::new(headers, rows)
+    }
+
+    /// Returns a new table pivoted on the first column, for key/value lookups.
+    ///
+    /// The first column's values become the new headers and the remaining
+    /// original headers become the first column. The top-left corner keeps the
+    /// original first header, so transposing the result again gives back the
+    /// original table. Unlike [`Table::transpose`], no `column`/`row_N` labels
+    /// are invented.
+    ///
+    /// A table with no columns transposes to an empty table, and one with a
+    /// single column to a headers-only table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["key".to_string(), "en".to_string(), "fr".to_string()],
+    ///     vec![
+    ///         vec!["yes".to_string(), "Yes".to_string(), "Oui".to_string()],
+    ///         vec!["no".to_string(), "No".to_string(), "Non".to_string()],
+    ///     ],
+    /// );
+    ///
+    /// let pivoted = table.transpose_by_first_column();
+    /// assert_eq!(pivoted.headers(), &["key", "yes", "no"]);
+    /// assert_eq!(pivoted.rows()[0], vec!["en", "Yes", "No"]);
+    /// assert_eq!(pivoted.rows()[1], vec!["fr", "Oui", "Non"]);
+    /// ```
+    pub fn transpose_by_first_column(&self) -> Table {
+        let Some(corner) = self.headers.first() else {
+            return Table::new(vec![], vec![]);
+        };
+
+        let mut headers = Vec::with_capacity(self.rows.len() + 1);
+        headers.push(corner.clone());
+        headers.extend(self.rows.iter().map(|row| row[0].clone()));
+
+        let rows = self
+            .headers
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(col, header)| {
+                let mut new_row = Vec::with_capacity(self.rows.len() + 1);
+                new_row.push(header.clone());
+                new_row.extend(self.rows.iter().map(|row| row[col].clone()));
+                new_row
+            })
+            .collect();
+
+        Table::new(headers, rows)
+    }
+
+    /// Unpivots a wide table into long form.
+    ///
+    /// The `id_vars` columns are kept, and every other column becomes one row
+    /// per input row holding the id values, the column's header under
+    /// `var_name` and its cell under `value_name`. Rows come out grouped by
+    /// input row, with the measured columns in their original order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Error::ColumnNotFound`] if an id column doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["city".to_string(), "jan".to_string(), "feb".to_string()],
+    ///     vec![vec!["Oslo".to_string(), "-4".to_string(), "-3".to_string()]],
+    /// );
+    ///
+    /// let long = table.melt(&["city"], "month", "temp").unwrap();
+    /// assert_eq!(long.headers(), &["city", "month", "temp"]);
+    /// assert_eq!(long.rows()[0], vec!["Oslo", "jan", "-4"]);
+    /// assert_eq!(long.rows()[1], vec!["Oslo", "feb", "-3"]);
+    /// ```
+    pub fn melt(&self, id_vars: &[&str], var_name: &str, value_name: &str) -> Result<Table> {
+        let ids = id_vars
+            .iter()
+            .map(|name| self.find_column(name))
+            .collect::<Result<Vec<usize>>>()?;
+        let measures: Vec<usize> = (0..self.headers.len())
+            .filter(|col| !ids.contains(col))
+            .collect();
+
+        let mut headers: Vec<String> = ids.iter().map(|&col| self.headers[col].clone()).collect();
+        headers.push(var_name.to_string());
+        headers.push(value_name.to_string());
+
+        let mut rows = Vec::with_capacity(self.rows.len() * measures.len());
+        for row in &self.rows {
+            for &measure in &measures {
+                let mut long_row = Vec::with_capacity(headers.len());
+                long_row.extend(ids.iter().map(|&col| row[col].clone()));
+                long_row.push(self.headers[measure].clone());
+                long_row.push(row[measure].clone());
+                rows.push(long_row);
+            }
+        }
+
+        Ok(Table::new(headers, rows))
+    }
+}
+
+/// Returns `true` if the pattern contains `*` or `?` wildcards.
+fn is_wildcard_pattern(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+/// Matches `text` against a simple glob pattern supporting `*` and `?`.
+///
+/// Uses a greedy scan with single-star backtracking, so matching runs in
+/// O(pattern * text) time in the worst case (no exponential blowup).
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<usize> = None;
+    let mut star_t = 0;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some(p);
+            star_t = t;
+            p += 1;
+        } else if let Some(star_p) = star {
+            // Let the last `*` absorb one more character and retry
+            p = star_p + 1;
+            star_t += 1;
+            t = star_t;
+        } else {
+            return false;
+        }
+    }
+
+    // Any trailing stars match the empty string
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Returns `headers` with repeats suffixed as [`Table::dedup_headers`] does
+pub(crate) fn deduped_headers(headers: &[String]) -> Vec<String> {
+    let mut deduped: Vec<String> = Vec::with_capacity(headers.len());
+    for header in headers {
+        let name = unique_header(&deduped, header);
+        deduped.push(name);
+    }
+    deduped
+}
+
+/// Returns `header`, suffixed with `_2`, `_3`, ... until it differs from
+/// every name in `existing`
+fn unique_header(existing: &[String], header: &str) -> String {
+    let mut name = header.to_string();
+    let mut suffix = 2;
+    while existing.contains(&name) {
+        name = format!("{}_{}", header, suffix);
+        suffix += 1;
+    }
+    name
+}
+
+/// Supported table formats for parsing and auto-detection.
+///
+/// This enum represents the various table formats that can be parsed by the library.
+/// Formats can be auto-detected or explicitly specified.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::Format;
+/// use std::str::FromStr;
+///
+/// // Parse format from string
+/// let format = Format::from_str("markdown").unwrap();
+/// assert_eq!(format, Format::Markdown);
+///
+/// // Case insensitive
+/// let format = Format::from_str("MySQL").unwrap();
+/// assert_eq!(format, Format::MySQL);
+///
+/// // Aliases are supported
+/// let format = Format::from_str("psql").unwrap();
+/// assert_eq!(format, Format::PostgreSQL);
+///
+/// // Display trait converts back to canonical string
+/// assert_eq!(format.to_string(), "postgresql");
+/// assert_eq!(Format::CSV.to_string(), "csv");
+///
+/// // Round-trip conversion works
+/// let original = Format::Markdown;
+/// let parsed = Format::from_str(&original.to_string()).unwrap();
+/// assert_eq!(original, parsed);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Format {
+    /// Markdown table format with pipe delimiters (e.g., `| col1 | col2 |`)
+    Markdown,
+
+    /// MySQL CLI output format with box-drawing characters (e.g., `+----+----+`)
+    MySQL,
+
+    /// PostgreSQL CLI output format with simple separators (e.g., `----+----`)
+    PostgreSQL,
+
+    /// Emacs org-mode table with `+` at column joins in its rules (e.g., `|---+---|`)
+    Org,
+
+    /// Comma-separated values (CSV) format
+    CSV,
+
+    /// Tab-separated values (TSV) format
+    TSV,
+
+    /// Pipe-separated values (PSV) format (e.g., `id|name`), without the
+    /// outer pipes and separator row of a Markdown table
+    Psv,
+
+    /// Plain text with one value per line, read into a single `value` column.
+    ///
+    /// Never auto-detected; select it explicitly with `lines`.
+    PlainLines,
+
+    /// `<table>` elements in an HTML page
+    ///
+    /// Never auto-detected; select it explicitly with `html`.
+    Html,
+
+    /// A JSON array of objects or of arrays (e.g., `[["id"],["1"]]`)
+    Json,
+}
+
+impl Format {
+    /// Returns every supported format, in detection precedence order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Format;
+    ///
+    /// assert!(Format::all().contains(&Format::CSV));
+    /// assert_eq!(Format::all().len(), 10);
+    /// ```
+    pub fn all() -> &'static [Format] {
+        &[
+            Format::Json,
+            Format::MySQL,
+            Format::PostgreSQL,
+            Format::Org,
+            Format::Markdown,
+            Format::Psv,
+            Format::TSV,
+            Format::CSV,
+            Format::PlainLines,
+            Format::Html,
+        ]
+    }
+
+    /// Returns the canonical lowercase name of this format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Format;
+    ///
+    /// assert_eq!(Format::PostgreSQL.as_str(), "postgresql");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Format::Markdown => "markdown",
+            Format::MySQL => "mysql",
+            Format::PostgreSQL => "postgresql",
+            Format::Org => "org",
+            Format::CSV => "csv",
+            Format::TSV => "tsv",
+            Format::Psv => "psv",
+            Format::PlainLines => "lines",
+            Format::Html => "html",
+            Format::Json => "json",
+        }
+    }
+
+    /// Returns the alternative names accepted for this format, besides its
+    /// canonical [`Display`](std::fmt::Display) name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Format;
+    ///
+    /// assert_eq!(Format::PostgreSQL.aliases(), &["postgres", "psql"]);
+    /// assert!(Format::CSV.aliases().is_empty());
+    /// ```
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Format::Markdown => &["md"],
+            Format::PostgreSQL => &["postgres", "psql"],
+            Format::Psv => &["pipe"],
+            Format::Org => &["orgtbl"],
+            Format::MySQL
+            | Format::CSV
+            | Format::TSV
+            | Format::PlainLines
+            | Format::Html
+            | Format::Json => &[],
+        }
+    }
+
+    /// Guesses a format from a file path's extension (case-insensitive).
+    ///
+    /// Recognizes `.csv`, `.tsv`/`.tab`, `.psv`, `.org`, `.json` and `.md`/`.markdown`; returns `None`
+    /// for anything else.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use table_extractor::Format;
+    ///
+    /// assert_eq!(Format::guess_from_extension(Path::new("out.CSV")), Some(Format::CSV));
+    /// assert_eq!(Format::guess_from_extension(Path::new("README.md")), Some(Format::Markdown));
+    /// assert_eq!(Format::guess_from_extension(Path::new("data")), None);
+    /// ```
+    pub fn guess_from_extension(path: &std::path::Path) -> Option<Format> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "csv" => Some(Format::CSV),
+            "tsv" | "tab" => Some(Format::TSV),
+            "psv" => Some(Format::Psv),
+            "org" => Some(Format::Org),
+            "json" => Some(Format::Json),
+            "md" | "markdown" => Some(Format::Markdown),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "markdown" | "md" => Ok(Format::Markdown),
+            "mysql" => Ok(Format::MySQL),
+            "postgres" | "postgresql" | "psql" => Ok(Format::PostgreSQL),
+            "csv" => Ok(Format::CSV),
+            "tsv" => Ok(Format::TSV),
+            "psv" | "pipe" => Ok(Format::Psv),
+            "org" | "orgtbl" => Ok(Format::Org),
+            "lines" => Ok(Format::PlainLines),
+            "html" => Ok(Format::Html),
+            "json" => Ok(Format::Json),
+            _ => Err(format!(
+                "Invalid format: '{}'. Valid formats: markdown, mysql, postgres, org, csv, tsv, psv, json, lines, html",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Trait for parsing table data from various input formats.
+///
+/// Implement this trait to add support for new table formats.
+/// All parsers should validate column consistency by using [`Table::new_validated`].
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::{Parser, Table, error::Result};
+///
+/// struct CustomParser;
+///
+/// impl Parser for CustomParser {
+///     fn parse(&self, input: &str) -> Result<Table> {
+///         // Parse input into headers and rows
+///         let headers = vec!["col1".to_string(), "col2".to_string()];
+///         let rows = vec![vec!["val1".to_string(), "val2".to_string()]];
+///
+///         // Use new_validated to ensure data integrity
+///         Table::new_validated(headers, rows)
+///     }
+/// }
+/// ```
+pub trait Parser {
+    /// Parses the input string into a table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input cannot be parsed or if the resulting
+    /// table fails validation (inconsistent columns, too many columns, etc.).
+    fn parse(&self, input: &str) -> Result<Table>;
+
+    /// Parses the input string, dropping data rows whose column count doesn't
+    /// match the header instead of failing.
+    ///
+    /// Returns the table built from the remaining rows together with a record
+    /// of each skipped row. The default implementation skips nothing and
+    /// behaves like [`Parser::parse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input cannot be parsed at all, or if the header
+    /// itself fails validation (e.g. too many columns).
+    fn parse_skipping_bad_rows(&self, input: &str) -> Result<(Table, Vec<SkippedRow>)> {
+        self.parse(input).map(|table| (table, Vec::new()))
+    }
+}
+
+/// Parses `input` as `format` with that format's default parser.
+///
+/// CSV, TSV and PSV use their standard delimiters; use
+/// [`parser::DelimitedParser`] directly for anything else.
+///
+/// # Errors
+///
+/// Returns any error from the parser, as [`Parser::parse`] does.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::{parse_with, Format};
+///
+/// let table = parse_with(Format::Psv, "id|name\n1|Alice").unwrap();
+/// assert_eq!(table.headers(), &["id", "name"]);
+/// ```
+pub fn parse_with(format: Format, input: &str) -> Result<Table> {
+    use parser::{
+        DelimitedParser, HtmlParser, JsonParser, LinesParser, MarkdownParser, MySqlParser,
+        OrgParser, PostgresParser,
+    };
+
+    match format {
+        Format::Markdown => MarkdownParser.parse(input),
+        Format::MySQL => MySqlParser.parse(input),
+        Format::PostgreSQL => PostgresParser.parse(input),
+        Format::Org => OrgParser.parse(input),
+        Format::Json => JsonParser.parse(input),
+        Format::PlainLines => LinesParser.parse(input),
+        Format::Html => HtmlParser::new().parse(input),
+        Format::CSV => DelimitedParser::new().delimiter(b',').parse(input),
+        Format::TSV => DelimitedParser::new().delimiter(b'\t').parse(input),
+        Format::Psv => DelimitedParser::new().delimiter(b'|').parse(input),
+    }
+}
+
+/// Detects the format of `input` and parses it, as `tabx` does by default.
+///
+/// Detection follows [`detector::detect_format`], so input with no
+/// delimiters at all is read as one-column CSV with its first line as the
+/// header.
+/// HTML is never detected; read it with [`parse_with`] and [`Format::Html`].
+///
+/// # Errors
+///
+/// Returns any error from the parser for the detected format.
+///
+/// # Examples
+///
+/// ```
+/// let input = "| id | name  |\n|----|-------|\n| 1  | Alice |";
+/// let table = table_extractor::parse_auto(input).unwrap();
+///
+/// assert_eq!(table.headers(), &["id", "name"]);
+/// assert_eq!(table.rows()[0], vec!["1", "Alice"]);
+/// ```
+pub fn parse_auto(input: &str) -> Result<Table> {
+    parse_with(detector::detect_format(input), input)
+}
+
+/// Data rows yielded one at a time by [`StreamParser::parse_streaming`]
+pub type RowStream<'a> = Box<dyn Iterator<Item = Result<Vec<String>>> + 'a>;
+
+/// Trait for parsers that can yield rows as they are read.
+///
+/// Unlike [`Parser::parse`], no [`Table`] is built, so only one row is held
+/// in memory at a time.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::parser::CsvParser;
+/// use table_extractor::StreamParser;
+///
+/// let (headers, rows) = CsvParser::csv()
+///     .parse_streaming("id,name\n1,Alice\n2,Bob")
+///     .unwrap();
+///
+/// assert_eq!(headers, vec!["id", "name"]);
+/// let names: Vec<String> = rows.map(|row| row.unwrap().remove(1)).collect();
+/// assert_eq!(names, vec!["Alice", "Bob"]);
+/// ```
+pub trait StreamParser {
+    /// Reads the header and returns it with an iterator over the data rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header can't be read. Errors in data rows,
+    /// such as an inconsistent column count, are yielded by the iterator.
+    fn parse_streaming<'a>(&self, input: &'a str) -> Result<(Vec<String>, RowStream<'a>)>;
+}
+
+/// A data row dropped by [`Parser::parse_skipping_bad_rows`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedRow {
+    /// Line number (1-indexed) of the row in the input
+    pub line: usize,
+    /// Number of columns in the header
+    pub expected: usize,
+    /// Number of columns found in the row
+    pub found: usize,
+}
+
+/// A parsed data row paired with its 1-indexed line number in the input
+pub(crate) type NumberedRow = (usize, Vec<String>);
+
+/// Builds a table from line-numbered rows, either failing on the first row
+/// whose width doesn't match the header or skipping such rows.
+///
+/// A failure is reported as [`error::Error::ParseErrorAt`] so it names the
+/// offending input line as well as the data row.
+pub(crate) fn table_from_numbered_rows(
+    headers: Vec<String>,
+    rows: Vec<NumberedRow>,
+    skip_bad_rows: bool,
+) -> Result<(Table, Vec<SkippedRow>)> {
+    if !skip_bad_rows {
+        let expected = headers.len();
+        if let Some((idx, (line, row))) = rows
+            .iter()
+            .enumerate()
+            .find(|(_, (_, row))| row.len() != expected)
+        {
+            let err = error::Error::InconsistentColumns {
+                row: idx + 1,
+                expected,
+                found: row.len(),
+            };
+            return Err(error::Error::ParseErrorAt {
+                line: *line,
+                message: err.to_string(),
+            });
+        }
+
+        let rows = rows.into_iter().map(|(_, row)| row).collect();
+        return Table::new_validated(headers, rows).map(|table| (table, Vec::new()));
+    }
+
+    let expected = headers.len();
+    let mut skipped = Vec::new();
+    let mut kept = Vec::with_capacity(rows.len());
+    for (line, row) in rows {
+        if row.len() == expected {
+            kept.push(row);
+        } else {
+            skipped.push(SkippedRow {
+                line,
+                expected,
+                found: row.len(),
+            });
+        }
+    }
+
+    Table::new_validated(headers, kept).map(|table| (table, skipped))
+}
+
+/// Trait for writing table data to various output formats.
+///
+/// Implement this trait to add support for new output formats.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::{Writer, Table, error::Result};
+/// use std::io::Write as IoWrite;
+///
+/// struct CustomWriter;
+///
+/// impl Writer for CustomWriter {
+///     fn write(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
+///         // Write headers
+///         writeln!(output, "{}", table.headers().join(","))?;
+///
+///         // Write rows
+///         for row in table.rows() {
+///             writeln!(output, "{}", row.join(","))?;
+///         }
+///
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait Writer {
+    /// Writes the table to the provided output stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails or if the table data is invalid
+    /// for the output format (e.g., delimiter conflicts).
+    fn write(&self, table: &Table, output: &mut dyn Write) -> Result<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_consistent_columns() {
+        let table = Table::new(
+            vec!["id".to_string(), "name".to_string()],
+            vec![
+                vec!["1".to_string(), "Alice".to_string()],
+                vec!["2".to_string(), "Bob".to_string()],
+            ],
+        );
+        assert!(table.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_inconsistent_columns() {
+        let table = Table::new(
+            vec!["id".to_string(), "name".to_string()],
+            vec![
+                vec!["1".to_string(), "Alice".to_string()],
+                vec!["2".to_string()], // Missing column
+            ],
+        );
+        let result = table.validate();
+        assert!(result.is_err());
+        if let Err(error::Error::InconsistentColumns {
+            row,
+            expected,
+            found,
+        }) = result
+        {
+            assert_eq!(row, 2);
+            assert_eq!(expected, 2);
+            assert_eq!(found, 1);
+        } else {
+            panic!("Expected InconsistentColumns error");
+        }
+    }
+
+    #[test]
+    fn test_new_validated_success() {
+        let result = Table::new_validated(
+            vec!["id".to_string(), "name".to_string()],
+            vec![
+                vec!["1".to_string(), "Alice".to_string()],
+                vec!["2".to_string(), "Bob".to_string()],
+            ],
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_new_validated_fails_on_inconsistent_columns() {
+        let result = Table::new_validated(
+            vec!["id".to_string(), "name".to_string()],
+            vec![
+                vec!["1".to_string(), "Alice".to_string()],
+                vec!["2".to_string()], // Missing column
+            ],
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_empty_table() {
+        let table = Table::new(vec![], vec![]);
+        assert!(table.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_no_rows() {
+        let table = Table::new(vec!["id".to_string(), "name".to_string()], vec![]);
+        assert!(table.validate().is_ok());
+    }
+
+    #[test]
+    fn test_new_validated_rejects_too_many_columns() {
+        let headers: Vec<String> = (0..10001).map(|i| format!("col{}", i)).collect();
+        let result = Table::new_validated(headers, vec![]);
+        assert!(result.is_err());
+        if let Err(error::Error::InvalidFormat(msg)) = result {
+            assert!(msg.contains("Too many columns"));
+            assert!(msg.contains("10001"));
+            assert!(msg.contains("10000"));
+        } else {
+            panic!("Expected InvalidFormat error");
+        }
+    }
+
+    #[test]
+    fn test_new_validated_accepts_max_columns() {
+        let headers: Vec<String> = (0..10000).map(|i| format!("col{}", i)).collect();
+        let result = Table::new_validated(headers, vec![]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_new_validated_accepts_just_under_max() {
+        let headers: Vec<String> = (0..9999).map(|i| format!("col{}", i)).collect();
+        let result = Table::new_validated(headers, vec![]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_select_matching_literal_and_wildcard() {
+        let table = Table::new(
+            vec![
+                "metric_cpu".to_string(),
+                "name".to_string(),
+                "id".to_string(),
+                "metric_mem".to_string(),
+            ],
+            vec![
+                vec![
+                    "0.5".to_string(),
+                    "web".to_string(),
+                    "1".to_string(),
+                    "128".to_string(),
+                ],
+                vec![
+                    "0.9".to_string(),
+                    "db".to_string(),
+                    "2".to_string(),
+                    "512".to_string(),
+                ],
+            ],
+        );
+
+        let selected = table.select_matching(&["id", "metric_*"]).unwrap();
+        assert_eq!(selected.headers(), &["id", "metric_cpu", "metric_mem"]);
+        assert_eq!(selected.rows()[0], vec!["1", "0.5", "128"]);
+        assert_eq!(selected.rows()[1], vec!["2", "0.9", "512"]);
+    }
+
+    #[test]
+    fn test_select_matching_unknown_column() {
+        let table = Table::new(vec!["id".to_string()], vec![]);
+
+        let result = table.select_matching(&["missing"]);
+        assert!(matches!(result, Err(error::Error::ColumnNotFound(name)) if name == "missing"));
+
+        let result = table.select_matching(&["metric_*"]);
+        assert!(matches!(result, Err(error::Error::ColumnNotFound(_))));
+    }
+
+    #[test]
+    fn test_select_matching_with_positions_tracks_repeated_literals() {
+        let table = Table::new(
+            vec![
+                "id".to_string(),
+                "metric_a".to_string(),
+                "metric_b".to_string(),
+            ],
+            vec![vec!["1".to_string(), "10".to_string(), "20".to_string()]],
+        );
+
+        let (selected, positions) = table
+            .select_matching_with_positions(&["id", "metric_*", "id"])
+            .unwrap();
+        assert_eq!(selected.headers(), &["id", "metric_a", "metric_b", "id"]);
+        assert_eq!(positions, vec![Some(0), None, Some(3)]);
+    }
+
+    #[test]
+    fn test_transpose_by_first_column_2x3() {
+        let table = Table::new(
+            vec!["h0".to_string(), "h1".to_string(), "h2".to_string()],
+            vec![
+                vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                vec!["d".to_string(), "e".to_string(), "f".to_string()],
+            ],
+        );
+
+        let pivoted = table.transpose_by_first_column();
+
+        assert_eq!(pivoted.headers(), &["h0", "a", "d"]);
+        assert_eq!(pivoted.rows().len(), 2);
+        assert_eq!(pivoted.rows()[0], vec!["h1", "b", "e"]);
+        assert_eq!(pivoted.rows()[1], vec!["h2", "c", "f"]);
+        assert_eq!(pivoted.transpose_by_first_column(), table);
+    }
+
+    #[test]
+    fn test_transpose_by_first_column_single_row() {
+        let table = Table::new(
+            vec!["key".to_string(), "value".to_string()],
+            vec![vec!["timeout".to_string(), "30".to_string()]],
+        );
+
+        let pivoted = table.transpose_by_first_column();
+
+        assert_eq!(pivoted.headers(), &["key", "timeout"]);
+        assert_eq!(pivoted.rows(), &[vec!["value", "30"]]);
+        assert!(Table::new(vec![], vec![])
+            .transpose_by_first_column()
+            .headers()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_transpose_empty_table() {
+        let table = Table::new(vec![], vec![]);
+        let transposed = table.transpose();
+        assert_eq!(transposed.column_count(), 0);
+        assert!(transposed.is_empty());
+    }
+
+    #[test]
+    fn test_transpose_headers_only() {
+        let table = Table::new(vec!["id".to_string(), "name".to_string()], vec![]);
+        let transposed = table.transpose();
+        assert_eq!(transposed.headers(), &["column"]);
+        assert_eq!(transposed.rows(), &[vec!["id"], vec!["name"]]);
+        assert!(transposed.validate().is_ok());
+    }
+
+    #[test]
+    fn test_transpose_single_row() {
+        let table = Table::new(
+            vec!["id".to_string(), "name".to_string(), "email".to_string()],
+            vec![vec![
+                "1".to_string(),
+                "Alice".to_string(),
+                "a@b.c".to_string(),
+            ]],
+        );
+        let transposed = table.transpose();
+        assert_eq!(transposed.headers(), &["column", "row_1"]);
+        assert_eq!(
+            transposed.rows(),
+            &[
+                vec!["id", "1"],
+                vec!["name", "Alice"],
+                vec!["email", "a@b.c"]
+            ]
+        );
+        assert!(transposed.validate().is_ok());
+    }
+
+    #[test]
+    fn test_concat_columns() {
+        let left = Table::new(
+            vec!["id".to_string(), "name".to_string()],
+            vec![
+                vec!["1".to_string(), "Alice".to_string()],
+                vec!["2".to_string(), "Bob".to_string()],
+            ],
+        );
+        let right = Table::new(
+            vec!["name".to_string(), "name_2".to_string()],
+            vec![
+                vec!["Smith".to_string(), "A".to_string()],
+                vec!["Jones".to_string(), "B".to_string()],
+            ],
+        );
+
+        let pasted = left.concat_columns(&right).unwrap();
+
+        assert_eq!(pasted.headers(), &["id", "name", "name_2", "name_2_2"]);
+        assert_eq!(pasted.rows()[1], vec!["2", "Bob", "Jones", "B"]);
+    }
+
+    #[test]
+    fn test_concat_columns_row_count_mismatch() {
+        let left = Table::new(vec!["a".to_string()], vec![vec!["1".to_string()]]);
+        let right = Table::new(vec!["b".to_string()], vec![]);
+
+        let err = left.concat_columns(&right).unwrap_err().to_string();
+        assert!(err.contains("Row count mismatch"));
+        assert!(err.contains("1 rows"));
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_normalize_unicode() {
+        let composed = "caf\u{e9}".to_string();
+        let decomposed = "cafe\u{301}".to_string();
+        let mut table = Table::new(
+            vec![decomposed.clone()],
+            vec![vec![composed.clone()], vec![decomposed]],
+        );
+
+        table.normalize_unicode();
+
+        assert_eq!(table.headers()[0], composed);
+        assert_eq!(table.rows()[0], table.rows()[1]);
+        assert_eq!(table.rows()[1][0].as_bytes(), composed.as_bytes());
+
+        // Idempotent
+        let before = table.rows().to_vec();
+        table.normalize_unicode();
+        assert_eq!(table.rows(), &before[..]);
+    }
+
+    #[test]
+    fn test_validate_strict_duplicate_header() {
+        let table = Table::new(
+            vec!["id".to_string(), "name".to_string(), "id".to_string()],
+            vec![vec!["1".to_string(), "Alice".to_string(), "2".to_string()]],
+        );
+
+        assert!(table.validate().is_ok());
+        let result = table.validate_strict();
+        assert!(matches!(
+            result,
+            Err(error::Error::DuplicateHeader { ref name }) if name == "id"
+        ));
+        assert_eq!(result.unwrap_err().to_string(), "Duplicate header: 'id'");
+    }
+
+    #[test]
+    fn test_validate_strict_unique_headers() {
+        let table = Table::new(
+            vec!["id".to_string(), "name".to_string()],
+            vec![vec!["1".to_string(), "Alice".to_string()]],
+        );
+
+        assert!(table.validate_strict().is_ok());
+    }
+
+    #[test]
+    fn test_rows_as_strings() {
+        let table = Table::new(
+            vec!["id".to_string(), "name".to_string()],
+            vec![
+                vec!["1".to_string(), "Alice".to_string()],
+                vec!["2".to_string(), "Bob".to_string()],
+            ],
+        );
+
+        assert_eq!(table.rows_as_strings(','), vec!["1,Alice", "2,Bob"]);
+    }
+
+    #[test]
+    fn test_transform_headers() {
+        let mut table = Table::new(
+            vec!["id".to_string(), "name".to_string()],
+            vec![vec!["1".to_string(), "Alice".to_string()]],
+        );
+
+        table.transform_headers(|idx, header| format!("{}_{}", header.to_uppercase(), idx));
+
+        assert_eq!(table.headers(), &["ID_0", "NAME_1"]);
+        assert_eq!(table.rows(), &[vec!["1", "Alice"]]);
+    }
+
+    #[test]
+    fn test_rename_then_dedup_headers() {
+        let mut table = Table::new(
+            vec![
+                "Order ID".to_string(),
+                "order id".to_string(),
+                "Total".to_string(),
+                "ORDER ID".to_string(),
+            ],
+            vec![vec![
+                "1".to_string(),
+                "2".to_string(),
+                "9.50".to_string(),
+                "3".to_string(),
+            ]],
+        );
+
+        table.rename_headers(|header| header.to_lowercase().replace(' ', "_"));
+        assert_eq!(
+            table.headers(),
+            &["order_id", "order_id", "total", "order_id"]
+        );
+
+        table.dedup_headers();
+        assert_eq!(
+            table.headers(),
+            &["order_id", "order_id_2", "total", "order_id_3"]
+        );
+        assert_eq!(table.rows(), &[vec!["1", "2", "9.50", "3"]]);
+    }
+
+    #[test]
+    fn test_fill_down() {
+        let mut table = Table::new(
+            vec!["category".to_string(), "item".to_string()],
+            vec![
+                vec!["".to_string(), "Orphan".to_string()],
+                vec!["Fruit".to_string(), "Apple".to_string()],
+                vec!["".to_string(), "Pear".to_string()],
+                vec![" ".to_string(), "Plum".to_string()],
+                vec!["Veg".to_string(), "Kale".to_string()],
+                vec!["".to_string(), "".to_string()],
+            ],
+        );
+
+        table.fill_down(&["category"]).unwrap();
+
+        let categories: Vec<&str> = table.rows().iter().map(|r| r[0].as_str()).collect();
+        assert_eq!(
+            categories,
+            vec!["", "Fruit", "Fruit", "Fruit", "Veg", "Veg"]
+        );
+        // Other columns are untouched
+        assert_eq!(table.rows()[5][1], "");
+    }
+
+    #[test]
+    fn test_fill_down_missing_column() {
+        let mut table = Table::new(
+            vec!["a".to_string()],
+            vec![vec!["1".to_string()], vec!["".to_string()]],
+        );
+
+        let result = table.fill_down(&["a", "b"]);
+
+        assert!(matches!(result, Err(error::Error::ColumnNotFound(ref name)) if name == "b"));
+        assert_eq!(table.rows()[1], vec![""]);
+    }
+
+    #[test]
+    fn test_to_html_and_markdown_string() {
+        let table = Table::new(
+            vec!["id".to_string(), "name".to_string(), "role".to_string()],
+            vec![vec![
+                "1".to_string(),
+                "Alice".to_string(),
+                "admin".to_string(),
+            ]],
+        );
+
+        let html = table.to_html_string();
+        assert!(html.contains("<table>"));
+        assert!(html.contains("<th>role</th>"));
+
+        let markdown = table.to_markdown_string().unwrap();
+        let separator = markdown.lines().nth(1).unwrap();
+        assert_eq!(separator, "|---|---|---|");
+    }
+
+    #[test]
+    fn test_value_counts_ordering() {
+        let table = Table::new(
+            vec!["v".to_string()],
+            ["b", "a", "c", "a", "b", ""]
+                .iter()
+                .map(|v| vec![v.to_string()])
+                .collect(),
+        );
+
+        let counts = table.value_counts("v").unwrap();
+        let counts: Vec<(&str, usize)> = counts.iter().map(|(v, c)| (v.as_str(), *c)).collect();
+        assert_eq!(counts, vec![("a", 2), ("b", 2), ("", 1), ("c", 1)]);
+
+        assert!(matches!(
+            table.value_counts("missing"),
+            Err(error::Error::ColumnNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_slice_rows_clamps() {
+        let table = Table::new(
+            vec!["n".to_string()],
+            (0..3).map(|n| vec![n.to_string()]).collect(),
+        );
+
+        assert_eq!(table.slice_rows(0, 2).rows().len(), 2);
+        assert!(table.slice_rows(2, 1).rows().is_empty());
+        assert!(table.slice_rows(5, 9).rows().is_empty());
+        assert_eq!(table.slice_rows(1, 9).headers(), &["n"]);
+    }
+
+    #[test]
+    fn test_trim_cells_keeps_internal_whitespace() {
+        let mut table = Table::new(
+            vec!["  h  ".to_string()],
+            vec![vec!["  a   b  ".to_string()]],
+        );
+
+        table.trim_cells(false);
+
+        assert_eq!(table.headers(), &["h"]);
+        assert_eq!(table.rows()[0][0], "a   b");
+    }
+
+    #[test]
+    fn test_trim_cells_collapses_internal_whitespace() {
+        let mut table = Table::new(
+            vec!["h".to_string()],
+            vec![vec!["  a   b  ".to_string()], vec!["\t\tc\td ".to_string()]],
+        );
+
+        table.trim_cells(true);
+
+        assert_eq!(table.rows()[0][0], "a b");
+        assert_eq!(table.rows()[1][0], "c d");
+    }
+
+    #[test]
+    fn test_sort_by_is_stable() {
+        let mut table = Table::new(
+            vec!["key".to_string(), "order".to_string()],
+            [("b", "1"), ("a", "2"), ("b", "3"), ("a", "4")]
+                .iter()
+                .map(|(k, o)| vec![k.to_string(), o.to_string()])
+                .collect(),
+        );
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        table.sort_by(|a, b| a[0].cmp(&b[0]));
+
+        let order: Vec<&str> = table.rows().iter().map(|r| r[1].as_str()).collect();
+        assert_eq!(order, vec!["2", "4", "1", "3"]);
+    }
 
     #[test]
-    fn test_validate_consistent_columns() {
+    fn test_rename_columns_missing_leaves_table_unchanged() {
+        let mut table = Table::new(vec!["a".to_string(), "b".to_string()], vec![]);
+
+        let result = table.rename_columns(&[("a", "x"), ("missing", "y")]);
+
+        assert!(matches!(result, Err(error::Error::ColumnNotFound(name)) if name == "missing"));
+        assert_eq!(table.headers(), &["a", "b"]);
+    }
+
+    #[test]
+    fn test_map_column_missing_column() {
+        let mut table = Table::new(vec!["id".to_string()], vec![vec!["1".to_string()]]);
+
+        let result = table.map_column("status", |value| value.to_string());
+
+        assert!(matches!(result, Err(error::Error::ColumnNotFound(name)) if name == "status"));
+        assert_eq!(table.rows()[0], vec!["1"]);
+    }
+
+    #[test]
+    fn test_strip_header_prefixes() {
+        let mut table = Table::new(
+            vec![
+                "users.id".to_string(),
+                "orders.id".to_string(),
+                "total".to_string(),
+                "a.b.c".to_string(),
+            ],
+            vec![vec![
+                "1".to_string(),
+                "9".to_string(),
+                "5".to_string(),
+                "x".to_string(),
+            ]],
+        );
+
+        table.strip_header_prefixes();
+
+        assert_eq!(table.headers(), &["id", "id_2", "total", "b.c"]);
+        assert_eq!(table.rows()[0], vec!["1", "9", "5", "x"]);
+    }
+
+    #[test]
+    fn test_append_table_moves_rows() {
+        let mut table = Table::new(vec!["id".to_string()], vec![vec!["0".to_string()]]);
+        let other = Table::new(
+            vec!["id".to_string()],
+            (1..=10_000).map(|n| vec![format!("row {}", n)]).collect(),
+        );
+        let first_cell = other.rows()[0][0].as_ptr();
+        let last_cell = other.rows()[9_999][0].as_ptr();
+
+        table.append_table(other).unwrap();
+
+        // Same heap buffers: the cells were moved, not cloned
+        assert_eq!(table.rows().len(), 10_001);
+        assert_eq!(table.rows()[1][0].as_ptr(), first_cell);
+        assert_eq!(table.rows()[10_000][0].as_ptr(), last_cell);
+        assert_eq!(table.rows()[10_000][0], "row 10000");
+    }
+
+    #[test]
+    fn test_append_table_header_mismatch() {
+        let mut table = Table::new(vec!["id".to_string()], vec![vec!["1".to_string()]]);
+        let other = Table::new(vec!["name".to_string()], vec![vec!["Bob".to_string()]]);
+
+        let err = table.append_table(other).unwrap_err();
+
+        assert!(matches!(err, error::Error::InvalidFormat(_)));
+        assert_eq!(
+            err.to_string(),
+            "Header mismatch: expected [id], found [name]"
+        );
+        assert_eq!(table.rows().len(), 1);
+    }
+
+    #[test]
+    fn test_explode_three_values() {
+        let mut table = Table::new(
+            vec!["id".to_string(), "tags".to_string(), "owner".to_string()],
+            vec![
+                vec!["1".to_string(), "a;b;c".to_string(), "Ann".to_string()],
+                vec!["2".to_string(), "".to_string(), "Bo".to_string()],
+            ],
+        );
+
+        table.explode("tags", ";").unwrap();
+
+        assert_eq!(
+            table.rows(),
+            &[
+                vec!["1", "a", "Ann"],
+                vec!["1", "b", "Ann"],
+                vec!["1", "c", "Ann"],
+                vec!["2", "", "Bo"],
+            ]
+        );
+        assert!(matches!(
+            table.explode("missing", ";"),
+            Err(error::Error::ColumnNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_select_columns_reorders_and_duplicates() {
+        let table = Table::new(
+            vec!["id".to_string(), "name".to_string()],
+            vec![vec!["1".to_string(), "Alice".to_string()]],
+        );
+
+        let selected = table.select_columns(&["name", "id", "name"]).unwrap();
+
+        assert_eq!(selected.headers(), &["name", "id", "name"]);
+        assert_eq!(selected.rows()[0], vec!["Alice", "1", "Alice"]);
+    }
+
+    #[test]
+    fn test_select_columns_missing() {
+        let table = Table::new(vec!["id".to_string()], vec![]);
+
+        let err = table.select_columns(&["id", "nmae"]).unwrap_err();
+
+        assert!(matches!(err, error::Error::InvalidFormat(_)));
+        assert!(err.to_string().contains("'nmae'"));
+    }
+
+    #[test]
+    fn test_select_columns_none() {
+        let table = Table::new(
+            vec!["id".to_string()],
+            vec![vec!["1".to_string()], vec!["2".to_string()]],
+        );
+
+        let selected = table.select_columns(&[]).unwrap();
+
+        assert!(selected.headers().is_empty());
+        assert_eq!(selected.column_count(), 0);
+        assert_eq!(selected.rows().len(), 2);
+    }
+
+    #[test]
+    fn test_row_chunks_boundaries() {
+        let table = Table::new(
+            vec!["n".to_string()],
+            (0..5).map(|n| vec![n.to_string()]).collect(),
+        );
+
+        let sizes: Vec<usize> = table.row_chunks(2).map(<[_]>::len).collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+
+        let empty = Table::new(vec!["n".to_string()], vec![]);
+        assert_eq!(empty.row_chunks(2).count(), 0);
+    }
+
+    #[test]
+    fn test_filter_rows_by_column_value() {
+        let table = Table::new(
+            vec!["id".to_string(), "team".to_string()],
+            vec![
+                vec!["1".to_string(), "red".to_string()],
+                vec!["2".to_string(), "blue".to_string()],
+                vec!["3".to_string(), "red".to_string()],
+            ],
+        );
+
+        let red = table.filter_rows(|row| row[1] == "red");
+
+        assert_eq!(red.headers(), table.headers());
+        assert_eq!(red.rows(), &[vec!["1", "red"], vec!["3", "red"]]);
+    }
+
+    #[test]
+    fn test_filter_rows_everything_out() {
+        let table = Table::new(
+            vec!["id".to_string(), "team".to_string()],
+            vec![vec!["1".to_string(), "red".to_string()]],
+        );
+
+        let none = table.filter_rows(|_| false);
+
+        assert!(none.is_empty());
+        assert_eq!(none.column_count(), 2);
+    }
+
+    #[test]
+    fn test_first_and_last_row() {
         let table = Table::new(
             vec!["id".to_string(), "name".to_string()],
             vec![
@@ -420,96 +2962,397 @@ mod tests {
                 vec!["2".to_string(), "Bob".to_string()],
             ],
         );
-        assert!(table.validate().is_ok());
+        assert_eq!(table.first_row().unwrap(), &["1", "Alice"]);
+        assert_eq!(table.last_row().unwrap(), &["2", "Bob"]);
+
+        let empty = Table::new(vec!["id".to_string()], vec![]);
+        assert_eq!(empty.first_row(), None);
+        assert_eq!(empty.last_row(), None);
     }
 
     #[test]
-    fn test_validate_inconsistent_columns() {
+    fn test_estimated_bytes() {
         let table = Table::new(
             vec!["id".to_string(), "name".to_string()],
             vec![
                 vec!["1".to_string(), "Alice".to_string()],
-                vec!["2".to_string()], // Missing column
+                vec!["2".to_string(), "".to_string()],
             ],
         );
-        let result = table.validate();
-        assert!(result.is_err());
-        if let Err(error::Error::InconsistentColumns {
-            row,
-            expected,
-            found,
-        }) = result
-        {
-            assert_eq!(row, 2);
-            assert_eq!(expected, 2);
-            assert_eq!(found, 1);
-        } else {
-            panic!("Expected InconsistentColumns error");
-        }
+
+        // 6 header bytes and 7 cell bytes, held in 6 strings across 2 rows
+        let overhead = 6 * std::mem::size_of::<String>() + 2 * std::mem::size_of::<Vec<String>>();
+        assert_eq!(table.estimated_bytes(), 13 + overhead);
+
+        let empty = Table::new(vec![], vec![]);
+        assert_eq!(empty.estimated_bytes(), 0);
     }
 
     #[test]
-    fn test_new_validated_success() {
-        let result = Table::new_validated(
+    fn test_column_values() {
+        let table = Table::new(
             vec!["id".to_string(), "name".to_string()],
             vec![
                 vec!["1".to_string(), "Alice".to_string()],
-                vec!["2".to_string(), "Bob".to_string()],
+                vec!["2".to_string(), "".to_string()],
             ],
         );
-        assert!(result.is_ok());
+
+        assert_eq!(table.column("name").unwrap(), vec!["Alice", ""]);
+        assert!(matches!(
+            table.column("email"),
+            Err(error::Error::ColumnNotFound(name)) if name == "email"
+        ));
     }
 
     #[test]
-    fn test_new_validated_fails_on_inconsistent_columns() {
-        let result = Table::new_validated(
+    fn test_as_parts_matches_accessors() {
+        let table = Table::new(
             vec!["id".to_string(), "name".to_string()],
             vec![
                 vec!["1".to_string(), "Alice".to_string()],
-                vec!["2".to_string()], // Missing column
+                vec!["2".to_string(), "Bob".to_string()],
             ],
         );
-        assert!(result.is_err());
+
+        let (headers, rows) = table.as_parts();
+
+        assert_eq!(headers, table.headers());
+        assert_eq!(rows, table.rows());
+        assert_eq!(
+            table.clone().into_parts(),
+            (headers.to_vec(), rows.to_vec())
+        );
     }
 
     #[test]
-    fn test_validate_empty_table() {
-        let table = Table::new(vec![], vec![]);
+    fn test_column_mutations_keep_table_rectangular() {
+        let mut table = Table::new(
+            vec!["id".to_string(), "tags".to_string(), "owner".to_string()],
+            vec![
+                vec!["1".to_string(), "a;b".to_string(), "Alice".to_string()],
+                vec!["2".to_string(), "c".to_string(), "".to_string()],
+            ],
+        );
+
+        table.add_row_numbers("#");
+        table.explode("tags", ";").unwrap();
+        table.fill_down(&["owner"]).unwrap();
+        table
+            .append_table(Table::new(
+                table.headers().to_vec(),
+                vec![vec![
+                    "9".to_string(),
+                    "3".to_string(),
+                    "d".to_string(),
+                    "Bob".to_string(),
+                ]],
+            ))
+            .unwrap();
+        table.rename_headers(|header| header.replace('#', "id"));
+        table.dedup_headers();
+        table.map_column("tags", |tag| tag.to_uppercase()).unwrap();
+
         assert!(table.validate().is_ok());
+        assert_eq!(table.column_count(), table.headers().len());
+        assert_eq!(table.headers(), &["id", "id_2", "tags", "owner"]);
+        assert!(table.rows().iter().all(|row| row.len() == 4));
+        assert_eq!(table.rows().len(), 4);
     }
 
     #[test]
-    fn test_validate_no_rows() {
-        let table = Table::new(vec!["id".to_string(), "name".to_string()], vec![]);
-        assert!(table.validate().is_ok());
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Table mutation broke the column invariant")]
+    fn test_column_mutations_reject_ragged_tables_in_debug_builds() {
+        let mut table = Table::new(
+            vec!["id".to_string(), "tags".to_string()],
+            vec![vec![
+                "1".to_string(),
+                "a;b".to_string(),
+                "extra".to_string(),
+            ]],
+        );
+
+        table.add_row_numbers("#");
     }
 
     #[test]
-    fn test_new_validated_rejects_too_many_columns() {
-        let headers: Vec<String> = (0..10001).map(|i| format!("col{}", i)).collect();
-        let result = Table::new_validated(headers, vec![]);
-        assert!(result.is_err());
-        if let Err(error::Error::InvalidFormat(msg)) = result {
-            assert!(msg.contains("Too many columns"));
-            assert!(msg.contains("10001"));
-            assert!(msg.contains("10000"));
-        } else {
-            panic!("Expected InvalidFormat error");
+    fn test_required_columns() {
+        let table = Table::new(
+            vec![
+                "id".to_string(),
+                "email".to_string(),
+                "name".to_string(),
+                "created".to_string(),
+            ],
+            vec![],
+        );
+
+        // A superset of the required columns passes
+        assert!(table.has_columns(&["email", "id"]));
+        assert!(table.has_columns(&[]));
+        assert!(table.missing_columns(&["email", "id"]).is_empty());
+
+        assert!(!table.has_columns(&["id", "Email", "phone"]));
+        assert_eq!(
+            table.missing_columns(&["id", "Email", "phone"]),
+            vec!["Email", "phone"]
+        );
+    }
+
+    #[test]
+    fn test_melt_wide_to_long() {
+        let table = Table::new(
+            vec![
+                "store".to_string(),
+                "year".to_string(),
+                "q1".to_string(),
+                "q2".to_string(),
+                "q3".to_string(),
+            ],
+            vec![
+                vec![
+                    "north".to_string(),
+                    "2023".to_string(),
+                    "10".to_string(),
+                    "12".to_string(),
+                    "9".to_string(),
+                ],
+                vec![
+                    "south".to_string(),
+                    "2023".to_string(),
+                    "7".to_string(),
+                    "".to_string(),
+                    "11".to_string(),
+                ],
+            ],
+        );
+
+        let long = table.melt(&["store", "year"], "quarter", "sales").unwrap();
+
+        assert_eq!(long.headers(), &["store", "year", "quarter", "sales"]);
+        assert_eq!(
+            long.rows(),
+            &[
+                vec!["north", "2023", "q1", "10"],
+                vec!["north", "2023", "q2", "12"],
+                vec!["north", "2023", "q3", "9"],
+                vec!["south", "2023", "q1", "7"],
+                vec!["south", "2023", "q2", ""],
+                vec!["south", "2023", "q3", "11"],
+            ]
+        );
+        assert!(long.validate().is_ok());
+
+        assert!(matches!(
+            table.melt(&["region"], "quarter", "sales"),
+            Err(error::Error::ColumnNotFound(name)) if name == "region"
+        ));
+    }
+
+    #[test]
+    fn test_remove_middle_row_shifts_later_rows() {
+        let mut table = Table::new(
+            vec!["id".to_string(), "name".to_string()],
+            vec![
+                vec!["1".to_string(), "Alice".to_string()],
+                vec!["2".to_string(), "Bob".to_string()],
+                vec!["3".to_string(), "Carol".to_string()],
+            ],
+        );
+
+        assert_eq!(
+            table.remove_row(1),
+            Some(vec!["2".to_string(), "Bob".to_string()])
+        );
+        assert_eq!(table.rows(), &[vec!["1", "Alice"], vec!["3", "Carol"]]);
+
+        // Carol moved up to index 1, so removing index 2 is now out of range
+        assert_eq!(table.remove_row(2), None);
+        assert_eq!(
+            table.remove_row(1),
+            Some(vec!["3".to_string(), "Carol".to_string()])
+        );
+        assert_eq!(table.rows().len(), 1);
+    }
+
+    #[test]
+    fn test_truncate_rows() {
+        let rows: Vec<Vec<String>> = (1..=4).map(|n| vec![n.to_string()]).collect();
+        let mut table = Table::new(vec!["n".to_string()], rows);
+
+        table.truncate_rows(10);
+        assert_eq!(table.rows().len(), 4);
+
+        table.truncate_rows(2);
+        assert_eq!(table.rows(), &[vec!["1"], vec!["2"]]);
+
+        table.truncate_rows(0);
+        assert!(table.is_empty());
+        assert_eq!(table.headers(), &["n"]);
+    }
+
+    #[test]
+    fn test_parse_auto_detects_each_format() {
+        let samples = [
+            ("| id | name  |\n|----|-------|\n| 1  | Alice |", "markdown"),
+            (
+                "+----+-------+\n| id | name  |\n+----+-------+\n| 1  | Alice |\n+----+-------+",
+                "mysql",
+            ),
+            (
+                " id | name\n----+-------\n  1 | Alice\n(1 row)",
+                "postgresql",
+            ),
+            ("| id | name  |\n|----+-------|\n| 1  | Alice |", "org"),
+            ("[{\"id\": \"1\", \"name\": \"Alice\"}]", "json"),
+            ("id,name\n1,Alice", "csv"),
+            ("id\tname\n1\tAlice", "tsv"),
+            ("id|name\n1|Alice", "psv"),
+        ];
+
+        for (input, format) in samples {
+            let table = parse_auto(input).unwrap();
+            assert_eq!(table.headers(), &["id", "name"], "{}", format);
+            assert_eq!(table.rows(), &[vec!["1", "Alice"]], "{}", format);
+
+            let format = Format::from_str(format).unwrap();
+            assert_eq!(parse_with(format, input).unwrap(), table);
         }
+
+        // HTML is never auto-detected, so it has to be asked for
+        let html =
+            "<table><tr><th>id</th><th>name</th></tr><tr><td>1</td><td>Alice</td></tr></table>";
+        let table = parse_with(Format::Html, html).unwrap();
+        assert_eq!(table.headers(), &["id", "name"]);
     }
 
     #[test]
-    fn test_new_validated_accepts_max_columns() {
-        let headers: Vec<String> = (0..10000).map(|i| format!("col{}", i)).collect();
-        let result = Table::new_validated(headers, vec![]);
-        assert!(result.is_ok());
+    fn test_parse_auto_reads_undelimited_input_as_csv() {
+        let table = parse_auto("apple\nbanana\ncherry").unwrap();
+        assert_eq!(table.headers(), &["apple"]);
+        assert_eq!(table.rows(), &[vec!["banana"], vec!["cherry"]]);
     }
 
     #[test]
-    fn test_new_validated_accepts_just_under_max() {
-        let headers: Vec<String> = (0..9999).map(|i| format!("col{}", i)).collect();
-        let result = Table::new_validated(headers, vec![]);
-        assert!(result.is_ok());
+    fn test_add_row_numbers() {
+        let mut table = Table::new(
+            vec!["id".to_string(), "name".to_string()],
+            vec![
+                vec!["10".to_string(), "Alice".to_string()],
+                vec!["20".to_string(), "Bob".to_string()],
+                vec!["30".to_string(), "Carol".to_string()],
+            ],
+        );
+
+        table.add_row_numbers("#");
+
+        assert_eq!(table.headers(), &["#", "id", "name"]);
+        let numbers: Vec<&str> = table.rows().iter().map(|r| r[0].as_str()).collect();
+        assert_eq!(numbers, vec!["1", "2", "3"]);
+        assert!(table.validate().is_ok());
+    }
+
+    #[test]
+    fn test_retain_columns_where_drops_fully_empty() {
+        let table = Table::new(
+            vec!["id".to_string(), "empty".to_string(), "partial".to_string()],
+            vec![
+                vec!["1".to_string(), "".to_string(), "x".to_string()],
+                vec!["2".to_string(), "".to_string(), "".to_string()],
+            ],
+        );
+
+        let kept = table.retain_columns_where(|_, values| values.iter().any(|v| !v.is_empty()));
+
+        assert_eq!(kept.headers(), &["id", "partial"]);
+        assert_eq!(kept.rows()[0], vec!["1", "x"]);
+        assert_eq!(kept.rows()[1], vec!["2", ""]);
+        assert!(kept.validate().is_ok());
+    }
+
+    #[test]
+    fn test_retain_columns_where_receives_header() {
+        let table = Table::new(
+            vec!["id".to_string(), "_internal".to_string()],
+            vec![vec!["1".to_string(), "x".to_string()]],
+        );
+
+        let kept = table.retain_columns_where(|header, _| !header.starts_with('_'));
+        assert_eq!(kept.headers(), &["id"]);
+    }
+
+    #[test]
+    fn test_headers_eq_ignoring_case() {
+        let upper = Table::new(
+            vec!["ID".to_string(), "Name".to_string()],
+            vec![vec!["1".to_string(), "Alice".to_string()]],
+        );
+        let lower = Table::new(
+            vec!["id".to_string(), "name".to_string()],
+            vec![vec!["2".to_string(), "Bob".to_string()]],
+        );
+
+        assert!(upper.headers() != lower.headers());
+        assert!(upper.headers_eq_ignoring_case(&lower));
+        assert!(Table::concat(vec![upper.clone(), lower.clone()]).is_err());
+
+        let merged = Table::concat_ignoring_header_case(vec![upper, lower]).unwrap();
+        assert_eq!(merged.headers(), &["ID", "Name"]);
+        assert_eq!(merged.rows().len(), 2);
+        assert_eq!(merged.rows()[1], vec!["2", "Bob"]);
+    }
+
+    #[test]
+    fn test_concat_header_mismatch() {
+        let a = Table::new(vec!["id".to_string()], vec![]);
+        let b = Table::new(vec!["name".to_string()], vec![]);
+
+        let result = Table::concat_ignoring_header_case(vec![a, b]);
+        assert!(matches!(result, Err(error::Error::InvalidFormat(msg)) if msg.contains("table 2")));
+    }
+
+    #[test]
+    fn test_take_columns() {
+        let headers: Vec<String> = (1..=10).map(|i| format!("c{}", i)).collect();
+        let row: Vec<String> = (1..=10).map(|i| i.to_string()).collect();
+        let table = Table::new(headers, vec![row]);
+
+        let preview = table.take_columns(3);
+        assert_eq!(preview.headers(), &["c1", "c2", "c3"]);
+        assert_eq!(preview.rows()[0], vec!["1", "2", "3"]);
+
+        assert_eq!(table.take_columns(50), table);
+        assert_eq!(table.take_columns(0).column_count(), 0);
+    }
+
+    #[test]
+    fn test_null_counts() {
+        let table = Table::new(
+            vec!["id".to_string(), "name".to_string(), "email".to_string()],
+            vec![
+                vec!["1".to_string(), "".to_string(), "".to_string()],
+                vec!["2".to_string(), "Bob".to_string(), "  ".to_string()],
+                vec!["3".to_string(), "".to_string(), "c@d.e".to_string()],
+            ],
+        );
+
+        assert_eq!(table.null_counts(), vec![0, 2, 2]);
+        assert_eq!(
+            Table::new(vec!["id".to_string()], vec![]).null_counts(),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("metric_*", "metric_cpu"));
+        assert!(wildcard_match("metric_*", "metric_"));
+        assert!(wildcard_match("*_id", "user_id"));
+        assert!(wildcard_match("col?", "col1"));
+        assert!(wildcard_match("a*b*c", "aXXbYYc"));
+        assert!(!wildcard_match("col?", "col10"));
+        assert!(!wildcard_match("metric_*", "name"));
     }
 
     #[test]
@@ -520,6 +3363,8 @@ mod tests {
         assert_eq!(Format::PostgreSQL.to_string(), "postgresql");
         assert_eq!(Format::CSV.to_string(), "csv");
         assert_eq!(Format::TSV.to_string(), "tsv");
+        assert_eq!(Format::Psv.to_string(), "psv");
+        assert_eq!(Format::Org.to_string(), "org");
     }
 
     #[test]
@@ -533,6 +3378,11 @@ mod tests {
             Format::PostgreSQL,
             Format::CSV,
             Format::TSV,
+            Format::Psv,
+            Format::Org,
+            Format::PlainLines,
+            Format::Html,
+            Format::Json,
         ];
 
         for format in formats {
@@ -541,4 +3391,13 @@ mod tests {
             assert_eq!(format, parsed, "Round-trip failed for {}", string);
         }
     }
+
+    #[test]
+    fn test_format_aliases_parse() {
+        for format in Format::all() {
+            for alias in format.aliases() {
+                assert_eq!(Format::from_str(alias).unwrap(), *format);
+            }
+        }
+    }
 }
@@ -1,13 +1,30 @@
-use clap::{CommandFactory, Parser as ClapParser, Subcommand};
+use clap::builder::{PossibleValue, PossibleValuesParser, StringValueParser, TypedValueParser};
+use clap::parser::ValueSource;
+use clap::{CommandFactory, FromArgMatches, Parser as ClapParser, Subcommand, ValueEnum};
 use clap_complete::{generate, Shell};
+use std::collections::HashMap;
 use std::fs;
-use std::io::{self, BufWriter, Read};
-use std::path::PathBuf;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
 use std::process;
-use table_extractor::detector::detect_format;
-use table_extractor::parser::{CsvParser, MarkdownParser, MySqlParser, PostgresParser};
-use table_extractor::writer::{CsvWriter, TsvWriter};
-use table_extractor::{Format, Parser, Writer};
+use table_extractor::detector::{
+    detect_format_from_lines_with_confidence, detect_format_with_confidence, is_plain_lines,
+    sniff_delimiter,
+};
+use table_extractor::error::Error;
+use table_extractor::parser::delimited::BOM;
+use table_extractor::parser::markdown::strip_list_markers;
+use table_extractor::parser::{
+    CsvParser, DelimitedParser, HtmlParser, JsonParser, LinesParser, MarkdownParser, MySqlParser,
+    OrgParser, PostgresParser,
+};
+use table_extractor::query::RowQuery;
+use table_extractor::types::NumericMode;
+use table_extractor::writer::{
+    write_table, CsvWriter, FlushEvery, JsonWriter, JsonlTypedWriter, NdJsonWriter, SqlWriter,
+    TreeWriter, TsvWriter, TypedTsvWriter,
+};
+use table_extractor::{Format, Parser, Table, Writer};
 
 /// Maximum input size: 100 MB
 /// Prevents DoS attacks via unbounded memory allocation
@@ -22,6 +39,25 @@ const EXIT_INVALID_ARGS: i32 = 2;
 /// I/O error exit code (file not found, permission denied, etc.)
 const EXIT_IO_ERROR: i32 = 3;
 
+/// Output formats accepted by `--output-format`
+const OUTPUT_FORMATS: &[&str] = &[
+    "tsv",
+    "csv",
+    "psv",
+    "typed-tsv",
+    "json",
+    "ndjson",
+    "jsonl-typed",
+    "markdown",
+    "mysql",
+    "sql",
+    "tree",
+    "debug",
+];
+
+/// Prefix of the comment line declaring a table's format
+const FORMAT_HINT_PREFIX: &str = "# format:";
+
 #[derive(ClapParser)]
 #[command(name = "tabx")]
 #[command(author = "Agustin Camino")]
@@ -31,25 +67,333 @@ struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
 
-    /// Force input format detection (auto, markdown, mysql, postgres, csv, tsv)
-    #[arg(short = 'i', long = "input-format", default_value = "auto")]
+    /// Force input format detection (auto, sniff, markdown, mysql, postgres, org, csv, tsv, psv, json, lines, html)
+    #[arg(
+        short = 'i',
+        long = "input-format",
+        default_value = "auto",
+        ignore_case = true,
+        global = true,
+        value_parser = input_format_values()
+    )]
     input_format: String,
 
-    /// Output format (tsv, csv)
-    #[arg(short = 'o', long = "output-format", default_value = "tsv")]
+    /// Output format (tsv, csv, psv, typed-tsv, json, ndjson, jsonl-typed, markdown, mysql, sql, tree, debug)
+    #[arg(
+        short = 'o',
+        long = "output-format",
+        default_value = "tsv",
+        value_parser = OutputFormatParser
+    )]
     output_format: String,
 
+    /// Pick output settings for a target tool; explicit -o wins over the preset
+    #[arg(
+        long = "for",
+        value_name = "TOOL",
+        value_enum,
+        conflicts_with = "retab"
+    )]
+    target_tool: Option<TargetTool>,
+
+    /// Start CSV output with a UTF-8 byte order mark
+    #[arg(long = "bom")]
+    bom: bool,
+
+    /// End CSV output lines with CRLF
+    #[arg(long = "crlf")]
+    crlf: bool,
+
+    /// Re-align a bordered or psql-style table into a correctly sized box (same as -o mysql)
+    #[arg(long = "retab", conflicts_with_all = ["output_format", "delimiter"])]
+    retab: bool,
+
+    /// Read input from FILE (same as the positional argument)
+    #[arg(
+        short = 'I',
+        long = "input",
+        value_name = "FILE",
+        conflicts_with = "inputs"
+    )]
+    input: Option<PathBuf>,
+
+    /// Write output to FILE instead of stdout (format inferred from its extension)
+    #[arg(short = 'O', long = "output", value_name = "FILE")]
+    output: Option<PathBuf>,
+
     /// Custom output delimiter (overrides --output-format)
     #[arg(short = 'd', long = "delimiter")]
     delimiter: Option<char>,
 
+    /// What to do when the TSV or custom output delimiter appears in the data
+    #[arg(long = "delimiter-conflict", value_enum, default_value_t = DelimiterConflict::Error)]
+    delimiter_conflict: DelimiterConflict,
+
+    /// Number of non-empty lines examined when detecting the input format [default: 30]
+    #[arg(long = "detect-lines", value_name = "N", global = true, value_parser = clap::value_parser!(u64).range(1..))]
+    detect_lines: Option<u64>,
+
+    /// Read the input as CSV when auto-detection is less confident than this (0.0 to 1.0)
+    #[arg(long = "detect-threshold", value_name = "SCORE", global = true, value_parser = parse_detect_threshold)]
+    detect_threshold: Option<f32>,
+
+    /// Discard the first N lines of input, such as connection banners, before detection
+    #[arg(
+        long = "skip-rows",
+        value_name = "N",
+        global = true,
+        default_value_t = 0
+    )]
+    skip_rows: usize,
+
+    /// Discard the last N non-blank lines of input, such as a report footer
+    #[arg(
+        long = "skip-trailing",
+        value_name = "N",
+        global = true,
+        default_value_t = 0
+    )]
+    skip_trailing: usize,
+
+    /// With -i html, read the Nth <table> on the page (0-based)
+    #[arg(long = "table-index", value_name = "N", global = true)]
+    table_index: Option<usize>,
+
+    /// With -i html, read the first <table> whose header row has all of these columns
+    #[arg(
+        long = "select-table-with-header",
+        value_name = "COLUMNS",
+        value_delimiter = ',',
+        global = true,
+        conflicts_with = "table_index"
+    )]
+    select_table_with_header: Vec<String>,
+
     /// Custom input delimiter for CSV/TSV
-    #[arg(long = "input-delimiter")]
+    #[arg(long = "input-delimiter", global = true)]
     input_delimiter: Option<char>,
 
-    /// Input file (reads from stdin if not provided)
+    /// Comma-separated columns to keep, in order (supports * and ? wildcards; `new=old` renames)
+    #[arg(short = 'c', long = "columns", value_delimiter = ',')]
+    columns: Vec<String>,
+
+    /// Comma-separated columns whose empty cells repeat the value above
+    #[arg(long = "fill-down", value_name = "COLUMNS", value_delimiter = ',')]
+    fill_down: Vec<String>,
+
+    /// Replace values in COLUMN using a `from,to` CSV lookup file (repeatable)
+    #[arg(long = "map-file", value_name = "COLUMN=FILE")]
+    map_file: Vec<String>,
+
+    /// Fail if any of these comma-separated columns is missing; extra columns are fine
+    #[arg(
+        long = "require-columns",
+        value_name = "COLUMNS",
+        value_delimiter = ','
+    )]
+    require_columns: Vec<String>,
+
+    /// Fail if any of these comma-separated columns has an empty cell
+    #[arg(
+        long = "assert-no-empty",
+        value_name = "COLUMNS",
+        value_delimiter = ','
+    )]
+    assert_no_empty: Vec<String>,
+
+    /// Keep rows matching EXPR, e.g. `status==active && (age>30 || vip==yes)`
+    #[arg(long = "select-rows", value_name = "EXPR")]
+    select_rows: Option<String>,
+
+    /// --select-rows parsed up front, so syntax errors show before input is read
+    #[arg(skip)]
+    row_query: Option<RowQuery>,
+
+    /// Split this column's cells on --explode-sep into one row per value
+    #[arg(long = "explode", value_name = "COLUMN")]
+    explode: Option<String>,
+
+    /// Separator used by --explode
+    #[arg(
+        long = "explode-sep",
+        value_name = "SEP",
+        default_value = ";",
+        requires = "explode",
+        value_parser = clap::builder::NonEmptyStringValueParser::new()
+    )]
+    explode_sep: String,
+
+    /// Sort rows by this column, numerically if every value is a number
+    #[arg(long = "sort", value_name = "COLUMN", group = "sort_column")]
+    sort: Option<String>,
+
+    /// Sort rows by this column, comparing values as numbers
+    #[arg(long = "sort-numeric", value_name = "COLUMN", group = "sort_column")]
+    sort_numeric: Option<String>,
+
+    /// Sort rows by this column, comparing values as text
+    #[arg(long = "sort-lexical", value_name = "COLUMN", group = "sort_column")]
+    sort_lexical: Option<String>,
+
+    /// Keep only the first N columns
+    #[arg(long = "first-columns", value_name = "N")]
+    first_columns: Option<usize>,
+
+    /// Remove columns whose every cell is empty
+    #[arg(long = "drop-empty-columns")]
+    drop_empty_columns: bool,
+
+    /// Unpivot to long form, keeping these comma-separated id columns
+    #[arg(
+        long = "melt",
+        value_name = "ID_COLUMNS",
+        value_delimiter = ',',
+        num_args = 1
+    )]
+    melt: Option<Vec<String>>,
+
+    /// Header of the column holding the former column names with --melt
+    #[arg(
+        long = "melt-var",
+        value_name = "NAME",
+        default_value = "variable",
+        requires = "melt"
+    )]
+    melt_var: String,
+
+    /// Header of the column holding the values with --melt
+    #[arg(
+        long = "melt-value",
+        value_name = "NAME",
+        default_value = "value",
+        requires = "melt"
+    )]
+    melt_value: String,
+
+    /// Swap rows and columns (headers become the first column)
+    #[arg(long = "transpose")]
+    transpose: bool,
+
+    /// Prepend a `#` column numbering the output rows from 1
+    #[arg(long = "row-numbers")]
+    row_numbers: bool,
+
+    /// Use a leading `# format: <name>` line to pick the input format
+    #[arg(long = "honor-format-hint", global = true)]
+    honor_format_hint: bool,
+
+    /// Skip input lines starting with CHAR
+    #[arg(long = "comment", value_name = "CHAR", global = true)]
+    comment: Option<char>,
+
+    /// Re-emit the lines skipped by --comment at the top of the output
+    #[arg(long = "preserve-comments", requires = "comment")]
+    preserve_comments: bool,
+
+    /// Prepend a `# format: <name>` comment line to the output
+    #[arg(long = "emit-format-comment")]
+    emit_format_comment: bool,
+
+    /// Prefix the header line with CHAR (default `#`) so awk and friends skip it
+    #[arg(
+        long = "comment-header",
+        value_name = "CHAR",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "#"
+    )]
+    comment_header: Option<char>,
+
+    /// Table name used in `-o sql` INSERT statements
+    #[arg(long = "sql-table", value_name = "NAME", default_value = "data")]
+    sql_table: String,
+
+    /// Indent JSON output with one key per line
+    #[arg(long = "pretty")]
+    pretty: bool,
+
+    /// Flush output after every N rows (default: flush once at the end)
+    #[arg(long = "flush-every", value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    flush_every: Option<u64>,
+
+    /// Remove leading `- `, `* ` or `+ ` list markers from Markdown table lines
+    #[arg(long = "strip-list-markers", global = true)]
+    strip_list_markers: bool,
+
+    /// Skip rows whose column count doesn't match the header, with a warning
+    #[arg(long = "keep-going", global = true)]
+    keep_going: bool,
+
+    /// Drop a trailing empty field that every CSV/TSV data row has beyond the header
+    #[arg(long = "trim-trailing-empty-columns", global = true)]
+    trim_trailing_empty_columns: bool,
+
+    /// Apply Unicode NFC normalization to all headers and cells
+    #[arg(long = "normalize-unicode", global = true)]
+    normalize_unicode: bool,
+
+    /// Reject tables with duplicate header names
+    #[arg(long = "strict-headers", global = true)]
+    strict_headers: bool,
+
+    /// Reject tables with an empty header name
+    #[arg(long = "reject-empty-headers", global = true)]
+    reject_empty_headers: bool,
+
+    /// Remove a leading `table.` qualifier from header names (`users.id` becomes `id`)
+    #[arg(long = "strip-table-prefix")]
+    strip_table_prefix: bool,
+
+    /// Treat headers differing only by case as equal when concatenating files
+    #[arg(long = "ignore-header-case", global = true)]
+    ignore_header_case: bool,
+
+    /// Input files, concatenated in order (reads from stdin if not provided)
     #[arg(value_name = "FILE")]
-    input: Option<PathBuf>,
+    inputs: Vec<PathBuf>,
+}
+
+/// Resolution policy for output delimiters found inside headers or cells
+#[derive(Clone, Copy, ValueEnum)]
+enum DelimiterConflict {
+    /// Fail with exit code 1
+    Error,
+    /// Quote fields CSV-style for the whole output
+    Quote,
+    /// Remove the delimiter characters from the data
+    Strip,
+}
+
+/// Output presets selected with `--for`
+#[derive(Clone, Copy, ValueEnum)]
+enum TargetTool {
+    /// CSV with a byte order mark and CRLF line endings
+    Excel,
+    /// Plain CSV, as `.import --csv` expects
+    Sqlite,
+    /// A Markdown table
+    Markdown,
+}
+
+/// How the input format was chosen, as reported by `-o debug`
+#[derive(Clone, Copy)]
+enum FormatOrigin {
+    /// Named with `--input-format`
+    Forced,
+    /// Taken from a `# format:` hint line
+    Hint,
+    /// Detected or sniffed from the content
+    Detected,
+}
+
+impl FormatOrigin {
+    fn describe(self) -> &'static str {
+        match self {
+            FormatOrigin::Forced => "forced",
+            FormatOrigin::Hint => "from hint",
+            FormatOrigin::Detected => "detected",
+        }
+    }
 }
 
 #[derive(Subcommand)]
@@ -60,6 +404,141 @@ enum Commands {
         #[arg(value_enum)]
         shell: Shell,
     },
+
+    /// Show row/column counts and per-column types and empty cells
+    Stats {
+        /// Input files, concatenated in order (reads from stdin if not provided)
+        #[arg(value_name = "FILE")]
+        inputs: Vec<PathBuf>,
+    },
+
+    /// Join two tables side by side, pairing rows by position
+    Paste {
+        /// Table whose columns come first
+        left: PathBuf,
+
+        /// Table whose columns are appended
+        right: PathBuf,
+    },
+
+    /// Select columns by name, in order (like `xsv select`)
+    Select {
+        /// Comma-separated column names (supports * and ? wildcards)
+        selection: String,
+
+        /// Input files, concatenated in order (reads from stdin if not provided)
+        #[arg(value_name = "FILE")]
+        inputs: Vec<PathBuf>,
+    },
+
+    /// Count distinct values per column (like `xsv frequency`)
+    Frequency {
+        /// Comma-separated columns to count (default: all columns)
+        #[arg(short = 's', long = "select")]
+        select: Option<String>,
+
+        /// Keep only the N most frequent values per column (0 for all)
+        #[arg(short = 'l', long = "limit", value_name = "N", default_value_t = 10)]
+        limit: usize,
+
+        /// Input files, concatenated in order (reads from stdin if not provided)
+        #[arg(value_name = "FILE")]
+        inputs: Vec<PathBuf>,
+    },
+
+    /// Check that the input survives being written and re-parsed in its own format
+    Verify {
+        /// Input files, concatenated in order (reads from stdin if not provided)
+        #[arg(value_name = "FILE")]
+        inputs: Vec<PathBuf>,
+    },
+
+    /// Keep a range of data rows (like `xsv slice`)
+    Slice {
+        /// First row to keep, 0-based
+        #[arg(short = 's', long = "start", value_name = "N")]
+        start: Option<usize>,
+
+        /// Row to stop before, 0-based
+        #[arg(short = 'e', long = "end", value_name = "N", conflicts_with = "len")]
+        end: Option<usize>,
+
+        /// Number of rows to keep
+        #[arg(short = 'l', long = "len", value_name = "N")]
+        len: Option<usize>,
+
+        /// Keep only the row at this 0-based index
+        #[arg(long = "index", value_name = "N", conflicts_with_all = ["start", "end", "len"])]
+        index: Option<usize>,
+
+        /// Input files, concatenated in order (reads from stdin if not provided)
+        #[arg(value_name = "FILE")]
+        inputs: Vec<PathBuf>,
+    },
+}
+
+/// Accepts any `--output-format` value so unsupported ones can be reported
+/// with a suggestion, while still advertising the known formats to completions
+#[derive(Clone)]
+struct OutputFormatParser;
+
+impl TypedValueParser for OutputFormatParser {
+    type Value = String;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &std::ffi::OsStr,
+    ) -> Result<String, clap::Error> {
+        StringValueParser::new().parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(OUTPUT_FORMATS.iter().map(PossibleValue::new)))
+    }
+}
+
+/// Parses a `--detect-threshold` value, which must lie between 0.0 and 1.0
+fn parse_detect_threshold(value: &str) -> Result<f32, String> {
+    match value.parse::<f32>() {
+        Ok(threshold) if (0.0..=1.0).contains(&threshold) => Ok(threshold),
+        Ok(_) => Err("must be between 0.0 and 1.0".to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Checks the requested output format against the available writers
+fn validate_output_format(requested: &str) -> Result<(), Error> {
+    if OUTPUT_FORMATS.contains(&requested) {
+        return Ok(());
+    }
+    Err(Error::UnsupportedOutput {
+        requested: requested.to_string(),
+        available: OUTPUT_FORMATS.iter().map(|f| f.to_string()).collect(),
+    })
+}
+
+/// Builds the accepted `--input-format` values from the supported formats,
+/// so shell completions and validation stay in sync with the library
+fn input_format_values() -> PossibleValuesParser {
+    let formats = Format::all().iter().map(|format| {
+        PossibleValue::new(format.as_str()).aliases(format.aliases().iter().copied())
+    });
+    let modes = [PossibleValue::new("auto"), PossibleValue::new("sniff")];
+    PossibleValuesParser::new(modes.into_iter().chain(formats))
+}
+
+/// Splits a leading `# format: <name>` hint line off the input.
+///
+/// Returns the hinted format name and the remaining input, or `None` if
+/// the first non-empty line isn't a format hint.
+fn take_format_hint(input: &str) -> Option<(&str, &str)> {
+    let start = input.len() - input.trim_start().len();
+    let rest = &input[start..];
+    let (line, remainder) = rest.split_once('\n').unwrap_or((rest, ""));
+    let name = line.trim_end().strip_prefix(FORMAT_HINT_PREFIX)?.trim();
+    Some((name, remainder))
 }
 
 /// Validates that a delimiter character is safe for CSV/TSV parsing
@@ -94,19 +573,47 @@ fn validate_delimiter(c: char, delimiter_type: &str) -> Result<u8, String> {
 }
 
 fn main() {
-    let cli = Cli::parse();
-
-    // Handle subcommands
-    if let Some(command) = cli.command {
-        match command {
-            Commands::Completions { shell } => {
-                let mut cmd = Cli::command();
-                generate(shell, &mut cmd, "tabx", &mut io::stdout());
-                return;
-            }
+    let matches = Cli::command().get_matches();
+    let mut cli = match Cli::from_arg_matches(&matches) {
+        Ok(cli) => cli,
+        Err(e) => e.exit(),
+    };
+
+    // An explicit -o always wins over the output file's extension
+    if matches.value_source("output_format") == Some(ValueSource::DefaultValue) {
+        if let Some(path) = &cli.output {
+            cli.output_format = output_format_for_path(path).to_string();
+        }
+    }
+    if cli.retab {
+        cli.output_format = "mysql".to_string();
+    }
+
+    // Presets fill in settings, but a format given with -o is kept
+    if let Some(tool) = cli.target_tool {
+        let format = match tool {
+            TargetTool::Excel => "csv",
+            TargetTool::Sqlite => "csv",
+            TargetTool::Markdown => "markdown",
+        };
+        if matches.value_source("output_format") != Some(ValueSource::CommandLine) {
+            cli.output_format = format.to_string();
+        }
+        // The byte order mark and line endings only apply to CSV, so they're
+        // dropped when -o picks another format
+        if matches!(tool, TargetTool::Excel) && cli.output_format == "csv" {
+            cli.bom = true;
+            cli.crlf = true;
         }
     }
 
+    // Completions don't read input, so they skip delimiter validation
+    if let Some(Commands::Completions { shell }) = cli.command {
+        let mut cmd = Cli::command();
+        generate(shell, &mut cmd, "tabx", &mut io::stdout());
+        return;
+    }
+
     // Validate custom delimiters early
     if let Some(delimiter) = cli.input_delimiter {
         if let Err(e) = validate_delimiter(delimiter, "input") {
@@ -115,6 +622,11 @@ fn main() {
         }
     }
 
+    if let Err(e) = validate_output_format(&cli.output_format) {
+        eprintln!("tabx: error: {}", e);
+        process::exit(EXIT_INVALID_ARGS);
+    }
+
     if let Some(delimiter) = cli.delimiter {
         if let Err(e) = validate_delimiter(delimiter, "output") {
             eprintln!("tabx: error: {}", e);
@@ -122,13 +634,119 @@ fn main() {
         }
     }
 
-    // Default behavior: convert table format
-    convert_table(cli);
+    if let Some(expr) = &cli.select_rows {
+        match RowQuery::parse(expr) {
+            Ok(query) => cli.row_query = Some(query),
+            Err(e) => {
+                eprintln!("tabx: error: {}", e);
+                process::exit(EXIT_INVALID_ARGS);
+            }
+        }
+    }
+
+    match cli.command.take() {
+        Some(Commands::Stats { inputs }) => {
+            let (_, _, table, _) = load_table(&cli, &inputs);
+            print_stats(&table);
+        }
+        Some(Commands::Verify { inputs }) => {
+            let (format, _, table, _) = load_table(&cli, &inputs);
+            verify_round_trip(format, &table);
+        }
+        Some(Commands::Paste { left, right }) => {
+            let (format, origin, left, mut comments) = load_table(&cli, &[left]);
+            let (_, _, right, right_comments) = load_table(&cli, &[right]);
+            comments.extend(right_comments);
+            let table = match left.concat_columns(&right) {
+                Ok(t) => t,
+                Err(e) => {
+                    eprintln!("tabx: error: {}", e);
+                    process::exit(EXIT_PARSE_ERROR);
+                }
+            };
+            convert_table(&cli, format, origin, table, &comments);
+        }
+        Some(Commands::Select { selection, inputs }) => {
+            let (format, origin, table, comments) = load_table(&cli, &inputs);
+            let patterns: Vec<&str> = selection.split(',').map(str::trim).collect();
+            let table = match table.select_matching(&patterns) {
+                Ok(t) => t,
+                Err(e) => {
+                    eprintln!("tabx: error: {}", e);
+                    process::exit(EXIT_INVALID_ARGS);
+                }
+            };
+            convert_table(&cli, format, origin, table, &comments);
+        }
+        Some(Commands::Frequency {
+            select,
+            limit,
+            inputs,
+        }) => {
+            let (format, origin, table, comments) = load_table(&cli, &inputs);
+            let table = frequency_table(&table, select.as_deref(), limit);
+            convert_table(&cli, format, origin, table, &comments);
+        }
+        Some(Commands::Slice {
+            start,
+            end,
+            len,
+            index,
+            inputs,
+        }) => {
+            let (format, origin, table, comments) = load_table(&cli, &inputs);
+            let (start, end) = match index {
+                Some(i) => (i, i.saturating_add(1)),
+                None => {
+                    let start = start.unwrap_or(0);
+                    let end = match len {
+                        Some(len) => start.saturating_add(len),
+                        None => end.unwrap_or(usize::MAX),
+                    };
+                    (start, end)
+                }
+            };
+            convert_table(
+                &cli,
+                format,
+                origin,
+                table.slice_rows(start, end),
+                &comments,
+            );
+        }
+        _ => {
+            // Default behavior: convert table format
+            let inputs = match &cli.input {
+                Some(path) => std::slice::from_ref(path),
+                None => cli.inputs.as_slice(),
+            };
+            let (format, origin, table, comments) = load_table(&cli, inputs);
+            convert_table(&cli, format, origin, table, &comments);
+        }
+    }
+}
+
+/// Picks the output format matching a file's extension, falling back to tsv
+fn output_format_for_path(path: &Path) -> &'static str {
+    // NDJSON and SQL are output-only, so they have no input Format to guess
+    for output_only in ["ndjson", "sql"] {
+        if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case(output_only))
+        {
+            return output_only;
+        }
+    }
+
+    match Format::guess_from_extension(path) {
+        Some(format) if OUTPUT_FORMATS.contains(&format.as_str()) => format.as_str(),
+        _ => "tsv",
+    }
 }
 
-fn convert_table(cli: Cli) {
-    // Read input from file or stdin
-    let input = if let Some(path) = &cli.input {
+/// Reads the input from a file, or from stdin when no path is given
+fn read_input(path: Option<&Path>) -> String {
+    if let Some(path) = path {
         // Read from file
         match fs::read_to_string(path) {
             Ok(content) => {
@@ -171,17 +789,104 @@ fn convert_table(cli: Cli) {
         }
 
         input
-    };
+    }
+}
 
-    // Handle empty input
+/// Detects the format of `input` and parses it, or returns `None` for blank input.
+///
+/// Lines skipped by `--comment` are appended to `comments`.
+fn parse_input(
+    cli: &Cli,
+    input: &str,
+    comments: &mut Vec<String>,
+) -> Option<(Format, FormatOrigin, Table)> {
+    // Windows tools prefix UTF-8 files with a byte order mark, which would
+    // otherwise hide the first line from detection and the format hint
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+    // Banners and footers go first so neither detection nor the hint sees them
+    let input = skip_lines(input, cli.skip_rows, cli.skip_trailing);
     if input.trim().is_empty() {
-        process::exit(EXIT_SUCCESS);
+        return None;
     }
 
+    // Strip a leading format hint; an explicit --input-format still wins
+    let mut hinted_format = None;
+    let input = if cli.honor_format_hint {
+        match take_format_hint(input) {
+            Some((name, rest)) => {
+                match name.parse::<Format>() {
+                    Ok(fmt) => hinted_format = Some(fmt),
+                    Err(err) => eprintln!("tabx: warning: Ignoring format hint: {}", err),
+                }
+                rest
+            }
+            None => input,
+        }
+    } else {
+        input
+    };
+
+    // Comments go after the hint is taken, since `#` would match it too
+    let uncommented;
+    let input = match cli.comment {
+        Some(marker) => {
+            uncommented = strip_comment_lines(input, marker, comments);
+            uncommented.as_str()
+        }
+        None => input,
+    };
+
+    // Bulleted tables must lose their markers before detection can see the pipes
+    let unbulleted;
+    let input = if cli.strip_list_markers {
+        unbulleted = strip_list_markers(input);
+        unbulleted.as_str()
+    } else {
+        input
+    };
+
     // Detect or parse input format
-    let format = if cli.input_format == "auto" {
-        detect_format(&input)
+    let mut sniffed_delimiter = None;
+    let mut origin = FormatOrigin::Detected;
+    let format = if cli.input_format.eq_ignore_ascii_case("auto") {
+        if hinted_format.is_some() {
+            origin = FormatOrigin::Hint;
+        }
+        let format = hinted_format.unwrap_or_else(|| {
+            let (format, confidence) = match cli.detect_lines {
+                Some(n) => detect_format_from_lines_with_confidence(input, n as usize),
+                None => detect_format_with_confidence(input),
+            };
+            match cli.detect_threshold {
+                Some(threshold) if format != Format::CSV && confidence < threshold => {
+                    eprintln!(
+                        "tabx: warning: Detected {} with confidence {:.2}, below the threshold of {:.2}; reading as CSV",
+                        format, confidence, threshold
+                    );
+                    Format::CSV
+                }
+                _ => format,
+            }
+        });
+        if format == Format::CSV && hinted_format.is_none() && is_plain_lines(input) {
+            eprintln!(
+                "tabx: warning: No delimiter found; reading the first line as the header (use -i lines for one value per line)"
+            );
+        }
+        format
+    } else if cli.input_format.eq_ignore_ascii_case("sniff") {
+        // Skip structural detection and treat the input as delimiter-separated
+        let delimiter = cli
+            .input_delimiter
+            .map_or_else(|| sniff_delimiter(input), |c| c as u8);
+        sniffed_delimiter = Some(delimiter);
+        if delimiter == b'\t' {
+            Format::TSV
+        } else {
+            Format::CSV
+        }
     } else {
+        origin = FormatOrigin::Forced;
         match cli.input_format.parse::<Format>() {
             Ok(fmt) => fmt,
             Err(err) => {
@@ -193,38 +898,676 @@ fn convert_table(cli: Cli) {
 
     // Select the appropriate parser
     let table = match format {
-        Format::Markdown => {
-            let parser = MarkdownParser;
-            parser.parse(&input)
+        Format::CSV | Format::TSV | Format::Psv if cli.trim_trailing_empty_columns => {
+            csv_parser(cli, format, sniffed_delimiter)
+                .parse_trimming_trailing_empty_column(input)
+                .map(|(table, repaired)| {
+                    if repaired {
+                        eprintln!(
+                            "tabx: warning: Dropped a trailing empty column present in every row"
+                        );
+                    }
+                    table
+                })
         }
-        Format::MySQL => {
-            let parser = MySqlParser;
-            parser.parse(&input)
+        _ => {
+            let parser: Box<dyn Parser> = match format {
+                Format::Markdown => Box::new(MarkdownParser),
+                Format::MySQL => Box::new(MySqlParser),
+                Format::PostgreSQL => Box::new(PostgresParser),
+                Format::Org => Box::new(OrgParser),
+                Format::Json => Box::new(JsonParser),
+                Format::PlainLines => Box::new(LinesParser),
+                Format::Html => Box::new(html_parser(cli)),
+                Format::CSV | Format::TSV | Format::Psv => {
+                    Box::new(csv_parser(cli, format, sniffed_delimiter))
+                }
+                // Format is non_exhaustive, but every variant it has today is handled above
+                _ => unreachable!("{} has no parser", format),
+            };
+            if cli.keep_going {
+                parser
+                    .parse_skipping_bad_rows(input)
+                    .map(|(table, skipped)| {
+                        for row in skipped {
+                            eprintln!(
+                                "tabx: warning: Skipping line {}: expected {} columns, found {}",
+                                row.line, row.expected, row.found
+                            );
+                        }
+                        table
+                    })
+            } else {
+                parser.parse(input)
+            }
         }
-        Format::PostgreSQL => {
-            let parser = PostgresParser;
-            parser.parse(&input)
+    };
+
+    match table {
+        Ok(t) => Some((format, origin, t)),
+        Err(e) => {
+            eprintln!("tabx: error: {}", e);
+            process::exit(EXIT_PARSE_ERROR);
         }
-        Format::CSV => {
-            let delimiter = cli.input_delimiter.unwrap_or(',') as u8;
-            let parser = CsvParser::new(delimiter);
-            parser.parse(&input)
+    }
+}
+
+/// Drops the first `leading` lines and the last `trailing` non-blank lines.
+///
+/// Blank lines at the end don't count, so a blank line printed after a
+/// footer doesn't need skipping on its own.
+fn skip_lines(input: &str, leading: usize, trailing: usize) -> &str {
+    let mut rest = input;
+    for _ in 0..leading {
+        rest = rest.split_once('\n').map_or("", |(_, after)| after);
+    }
+    for _ in 0..trailing {
+        rest = rest
+            .trim_end()
+            .rsplit_once('\n')
+            .map_or("", |(before, _)| before);
+    }
+    rest
+}
+
+/// Removes the lines starting with `marker`, collecting them into `comments`.
+///
+/// Later lines shift up, so parse errors report lines of the remaining input.
+fn strip_comment_lines(input: &str, marker: char, comments: &mut Vec<String>) -> String {
+    let mut kept = String::with_capacity(input.len());
+    for line in input.split_inclusive('\n') {
+        if line.starts_with(marker) {
+            comments.push(line.trim_end_matches(['\r', '\n']).to_string());
+        } else {
+            kept.push_str(line);
         }
-        Format::TSV => {
-            let delimiter = cli.input_delimiter.unwrap_or('\t') as u8;
-            let parser = CsvParser::new(delimiter);
-            parser.parse(&input)
+    }
+    kept
+}
+
+/// Builds the HTML parser for the table chosen on the command line
+fn html_parser(cli: &Cli) -> HtmlParser {
+    let parser = HtmlParser::new();
+    if !cli.select_table_with_header.is_empty() {
+        let names: Vec<&str> = cli
+            .select_table_with_header
+            .iter()
+            .map(|name| name.trim())
+            .collect();
+        parser.with_header(&names)
+    } else {
+        parser.table_index(cli.table_index.unwrap_or(0))
+    }
+}
+
+/// Builds a delimiter-separated writer, commenting out the header if asked
+fn tsv_writer(cli: &Cli, delimiter: char) -> TsvWriter {
+    let writer = TsvWriter::new(delimiter);
+    match cli.comment_header {
+        Some(marker) => writer.comment_header(marker),
+        None => writer,
+    }
+}
+
+/// Builds a CSV-quoting writer, commenting out the header if asked
+fn csv_writer(cli: &Cli, delimiter: u8) -> CsvWriter {
+    let mut writer = CsvWriter::with_delimiter(delimiter);
+    if cli.crlf {
+        writer = writer.crlf();
+    }
+    match cli.comment_header {
+        Some(marker) => writer.comment_header(marker),
+        None => writer,
+    }
+}
+
+/// Builds the CSV/TSV/PSV parser, honoring a sniffed or explicit input delimiter
+fn csv_parser(cli: &Cli, format: Format, sniffed_delimiter: Option<u8>) -> DelimitedParser {
+    let default = match format {
+        Format::CSV => ',',
+        Format::Psv => '|',
+        _ => '\t',
+    };
+    let delimiter =
+        sniffed_delimiter.unwrap_or_else(|| cli.input_delimiter.unwrap_or(default) as u8);
+    DelimitedParser::new().delimiter(delimiter)
+}
+
+/// Builds a `field`/`value`/`count` table of the most frequent values in
+/// each selected column
+fn frequency_table(table: &Table, select: Option<&str>, limit: usize) -> Table {
+    let columns: Vec<&str> = match select {
+        Some(select) => select.split(',').map(str::trim).collect(),
+        None => table.headers().iter().map(String::as_str).collect(),
+    };
+
+    let mut rows = Vec::new();
+    for column in columns {
+        let counts = match table.value_counts(column) {
+            Ok(counts) => counts,
+            Err(e) => {
+                eprintln!("tabx: error: {}", e);
+                process::exit(EXIT_INVALID_ARGS);
+            }
+        };
+        let keep = if limit == 0 { counts.len() } else { limit };
+        rows.extend(
+            counts
+                .into_iter()
+                .take(keep)
+                .map(|(value, count)| vec![column.to_string(), value, count.to_string()]),
+        );
+    }
+
+    Table::new(
+        vec![
+            "field".to_string(),
+            "value".to_string(),
+            "count".to_string(),
+        ],
+        rows,
+    )
+}
+
+/// Reads and parses every input, concatenating them into one table.
+///
+/// Also returns the lines skipped by `--comment`, in input order. Exits
+/// successfully without output if all inputs are blank.
+fn load_table(cli: &Cli, inputs: &[PathBuf]) -> (Format, FormatOrigin, Table, Vec<String>) {
+    let mut tables = Vec::with_capacity(inputs.len().max(1));
+    let mut comments = Vec::new();
+    if inputs.is_empty() {
+        tables.extend(parse_input(cli, &read_input(None), &mut comments));
+    } else {
+        for path in inputs {
+            tables.extend(parse_input(cli, &read_input(Some(path)), &mut comments));
+        }
+    }
+
+    // Handle empty input
+    if tables.is_empty() {
+        process::exit(EXIT_SUCCESS);
+    }
+
+    // Multiple files may differ in format; the first one is reported
+    let (format, origin, _) = tables[0];
+    let mut tables: Vec<Table> = tables.into_iter().map(|(_, _, table)| table).collect();
+
+    // Normalize before concatenating so headers spelled in different forms still match
+    if cli.normalize_unicode {
+        for table in &mut tables {
+            normalize_unicode(table);
+        }
+    }
+
+    let table = if tables.len() == 1 {
+        tables.remove(0)
+    } else {
+        let merged = if cli.ignore_header_case {
+            Table::concat_ignoring_header_case(tables)
+        } else {
+            Table::concat(tables)
+        };
+        match merged {
+            Ok(t) => t,
+            Err(e) => {
+                eprintln!("tabx: error: {}", e);
+                process::exit(EXIT_PARSE_ERROR);
+            }
+        }
+    };
+
+    if cli.strict_headers {
+        if let Err(e) = table.validate_strict() {
+            eprintln!("tabx: error: {}", e);
+            process::exit(EXIT_PARSE_ERROR);
+        }
+    }
+
+    if cli.reject_empty_headers && table.has_empty_headers() {
+        let column = table
+            .headers()
+            .iter()
+            .position(String::is_empty)
+            .unwrap_or(0);
+        eprintln!("tabx: error: Header of column {} is empty", column + 1);
+        process::exit(EXIT_PARSE_ERROR);
+    }
+
+    (format, origin, table, comments)
+}
+
+#[cfg(feature = "unicode")]
+fn normalize_unicode(table: &mut Table) {
+    table.normalize_unicode();
+}
+
+#[cfg(not(feature = "unicode"))]
+fn normalize_unicode(_table: &mut Table) {
+    eprintln!("tabx: error: --normalize-unicode requires the 'unicode' feature");
+    process::exit(EXIT_INVALID_ARGS);
+}
+
+/// Reports a failed write and exits.
+///
+/// A closed pipe (e.g. piping into `head`) means the reader has all it wants,
+/// so like other Unix tools we exit successfully without a message.
+fn exit_on_write_error(e: Error) -> ! {
+    if let Error::IoError(io_err) = &e {
+        if io_err.kind() == io::ErrorKind::BrokenPipe {
+            process::exit(EXIT_SUCCESS);
+        }
+    }
+    eprintln!("tabx: error: {}", e);
+    process::exit(EXIT_IO_ERROR);
+}
+
+/// Writes `table` in `format`, parses the result back and reports the first
+/// difference from the original, exiting 1 if the round trip is lossy
+fn verify_round_trip(format: Format, table: &Table) {
+    let mut written = Vec::new();
+    if let Err(e) = write_table(table, format, &mut written) {
+        eprintln!(
+            "tabx: error: Verification failed: cannot write {}: {}",
+            format, e
+        );
+        process::exit(EXIT_PARSE_ERROR);
+    }
+    let parser: Box<dyn Parser> = match format {
+        Format::CSV => Box::new(CsvParser::csv()),
+        Format::TSV => Box::new(CsvParser::tsv()),
+        Format::Psv => Box::new(CsvParser::new(b'|')),
+        Format::Markdown => Box::new(MarkdownParser),
+        Format::MySQL => Box::new(MySqlParser),
+        Format::Json => Box::new(JsonParser),
+        Format::Html => Box::new(HtmlParser::new()),
+        // write_table has already rejected every format without a writer
+        _ => unreachable!("{} has no writer", format),
+    };
+    let written = String::from_utf8_lossy(&written);
+    let reparsed = match parser.parse(&written) {
+        Ok(reparsed) => reparsed,
+        Err(e) => {
+            eprintln!(
+                "tabx: error: Verification failed: cannot re-parse {} output: {}",
+                format, e
+            );
+            process::exit(EXIT_PARSE_ERROR);
         }
     };
 
-    let table = match table {
+    if let Some(difference) = first_difference(table, &reparsed) {
+        eprintln!(
+            "tabx: error: Verification failed for {}: {}",
+            format, difference
+        );
+        process::exit(EXIT_PARSE_ERROR);
+    }
+
+    println!(
+        "{} round trip OK: {} rows, {} columns",
+        format,
+        table.rows().len(),
+        table.column_count()
+    );
+}
+
+/// Describes the first way `actual` differs from `expected`, if any
+fn first_difference(expected: &Table, actual: &Table) -> Option<String> {
+    if expected.headers() != actual.headers() {
+        return Some(format!(
+            "headers differ: expected [{}], found [{}]",
+            expected.headers().join(", "),
+            actual.headers().join(", ")
+        ));
+    }
+    if expected.rows().len() != actual.rows().len() {
+        return Some(format!(
+            "row count differs: expected {}, found {}",
+            expected.rows().len(),
+            actual.rows().len()
+        ));
+    }
+
+    for (idx, (want, got)) in expected.rows().iter().zip(actual.rows()).enumerate() {
+        let mut cells = expected.headers().iter().zip(want.iter().zip(got));
+        if let Some((header, (want, got))) = cells.find(|(_, (w, g))| w != g) {
+            return Some(format!(
+                "row {}, column '{}': expected {:?}, found {:?}",
+                idx + 1,
+                header,
+                want,
+                got
+            ));
+        }
+    }
+
+    None
+}
+
+/// Prints table dimensions followed by a per-column summary as TSV
+fn print_stats(table: &Table) {
+    let types = table.infer_types();
+    let row_count = table.rows().len();
+
+    let rows = table
+        .headers()
+        .iter()
+        .zip(&types)
+        .zip(table.null_counts())
+        .map(|((header, ty), nulls)| {
+            let percent = if row_count == 0 {
+                0.0
+            } else {
+                nulls as f64 * 100.0 / row_count as f64
+            };
+            vec![
+                header.clone(),
+                ty.to_string(),
+                nulls.to_string(),
+                format!("{:.1}%", percent),
+            ]
+        })
+        .collect();
+    let summary = Table::new(
+        vec![
+            "column".to_string(),
+            "type".to_string(),
+            "empty".to_string(),
+            "empty_pct".to_string(),
+        ],
+        rows,
+    );
+
+    let mut stdout = BufWriter::new(io::stdout());
+    let result = writeln!(
+        stdout,
+        "rows: {}\ncolumns: {}\napprox size: {} bytes\n",
+        row_count,
+        table.column_count(),
+        table.estimated_bytes()
+    )
+    .map_err(Into::into)
+    .and_then(|_| TsvWriter::default().write(&summary, &mut stdout));
+
+    if let Err(e) = result {
+        exit_on_write_error(e);
+    }
+}
+
+/// Reads a `--map-file` lookup: a CSV whose header is followed by `from,to` rows
+fn load_value_map(path: &Path) -> HashMap<String, String> {
+    let table = match CsvParser::csv().parse(&read_input(Some(path))) {
+        Ok(table) => table,
+        Err(e) => {
+            eprintln!("tabx: error: Map file {}: {}", path.display(), e);
+            process::exit(EXIT_PARSE_ERROR);
+        }
+    };
+    if table.headers().len() != 2 {
+        eprintln!(
+            "tabx: error: Map file {} must have two columns (from,to), found {}",
+            path.display(),
+            table.headers().len()
+        );
+        process::exit(EXIT_INVALID_ARGS);
+    }
+
+    let (_, rows) = table.into_parts();
+    rows.into_iter()
+        .map(|mut row| {
+            let to = row.pop().unwrap_or_default();
+            let from = row.pop().unwrap_or_default();
+            (from, to)
+        })
+        .collect()
+}
+
+/// Exits 1 if any of the named columns contains an empty cell, or 2 if a
+/// column doesn't exist
+fn check_no_empty(table: &Table, columns: &[String]) {
+    let names: Vec<&str> = columns.iter().map(|c| c.trim()).collect();
+    let subset = match table.select_matching(&names) {
         Ok(t) => t,
         Err(e) => {
             eprintln!("tabx: error: {}", e);
+            process::exit(EXIT_INVALID_ARGS);
+        }
+    };
+
+    for (col, empty) in subset.null_counts().into_iter().enumerate() {
+        if empty == 0 {
+            continue;
+        }
+        let first = subset
+            .rows()
+            .iter()
+            .position(|row| row[col].trim().is_empty())
+            .map_or(0, |idx| idx + 1);
+        eprintln!(
+            "tabx: error: Column '{}' has {} empty cell(s), first at row {}",
+            subset.headers()[col],
+            empty,
+            first
+        );
+        process::exit(EXIT_PARSE_ERROR);
+    }
+}
+
+/// Describes every header and cell containing the output delimiter, so the
+/// source can be fixed in one pass
+fn find_delimiter_conflicts(table: &Table, delimiter: char) -> Vec<String> {
+    let shown = delimiter.escape_default();
+    let mut conflicts: Vec<String> = table
+        .headers()
+        .iter()
+        .filter(|header| header.contains(delimiter))
+        .map(|header| {
+            format!(
+                "Header '{}' contains delimiter character '{}'",
+                header, shown
+            )
+        })
+        .collect();
+
+    for (idx, row) in table.rows().iter().enumerate() {
+        for (header, cell) in table.headers().iter().zip(row) {
+            if cell.contains(delimiter) {
+                conflicts.push(format!(
+                    "Row {} contains delimiter character '{}' in column '{}'",
+                    idx + 1,
+                    shown,
+                    header
+                ));
+            }
+        }
+    }
+
+    conflicts
+}
+
+/// Applies the column and row transforms, then writes the table in the requested format.
+///
+/// `comments` are the input lines skipped by `--comment`, written first with
+/// `--preserve-comments`.
+fn convert_table(
+    cli: &Cli,
+    format: Format,
+    origin: FormatOrigin,
+    mut table: Table,
+    comments: &[String],
+) {
+    // Strip first so every name-based option sees the short names
+    if cli.strip_table_prefix {
+        table.strip_header_prefixes();
+    }
+
+    // Check the input's schema before any option renames or drops columns
+    if !cli.require_columns.is_empty() {
+        let required: Vec<&str> = cli.require_columns.iter().map(|c| c.trim()).collect();
+        let missing = table.missing_columns(&required);
+        if !missing.is_empty() {
+            eprintln!(
+                "tabx: error: Missing required column(s): {}",
+                missing.join(", ")
+            );
             process::exit(EXIT_PARSE_ERROR);
         }
+    }
+
+    // Fill before projecting so the named columns refer to the input's headers
+    if !cli.fill_down.is_empty() {
+        let columns: Vec<&str> = cli.fill_down.iter().map(|c| c.trim()).collect();
+        if let Err(e) = table.fill_down(&columns) {
+            eprintln!("tabx: error: {}", e);
+            process::exit(EXIT_INVALID_ARGS);
+        }
+    }
+
+    // Remap after filling so the repeated values are mapped too
+    for spec in &cli.map_file {
+        let Some((column, path)) = spec.split_once('=') else {
+            eprintln!(
+                "tabx: error: Invalid --map-file '{}': expected COLUMN=FILE",
+                spec
+            );
+            process::exit(EXIT_INVALID_ARGS);
+        };
+        let map = load_value_map(Path::new(path.trim()));
+        let result = table.map_column(column.trim(), |value| {
+            map.get(value).cloned().unwrap_or_else(|| value.to_string())
+        });
+        if let Err(e) = result {
+            eprintln!("tabx: error: {}", e);
+            process::exit(EXIT_INVALID_ARGS);
+        }
+    }
+
+    if !cli.assert_no_empty.is_empty() {
+        check_no_empty(&table, &cli.assert_no_empty);
+    }
+
+    if let Some(column) = &cli.explode {
+        if let Err(e) = table.explode(column.trim(), &cli.explode_sep) {
+            eprintln!("tabx: error: {}", e);
+            process::exit(EXIT_INVALID_ARGS);
+        }
+    }
+
+    // Select rows before projecting so they can be matched on a column that's dropped
+    if let Some(query) = &cli.row_query {
+        match table.select_rows(query) {
+            Ok(t) => table = t,
+            Err(e) => {
+                eprintln!("tabx: error: {}", e);
+                process::exit(EXIT_INVALID_ARGS);
+            }
+        }
+    }
+
+    // Sort before projecting so rows can be ordered by a column that's dropped
+    let sort = [
+        (&cli.sort, NumericMode::Auto),
+        (&cli.sort_numeric, NumericMode::Numeric),
+        (&cli.sort_lexical, NumericMode::Lexical),
+    ]
+    .into_iter()
+    .find_map(|(column, mode)| column.as_deref().map(|column| (column, mode)));
+    if let Some((column, mode)) = sort {
+        if let Err(e) = table.sort_by_column(column.trim(), mode) {
+            eprintln!("tabx: error: {}", e);
+            process::exit(EXIT_INVALID_ARGS);
+        }
+    }
+
+    // Project columns before the delimiter check so dropped columns can't conflict
+    let table = if cli.columns.is_empty() {
+        table
+    } else {
+        // `new=old` entries select `old` and rename the column it lands in.
+        // Renaming goes by output position rather than through
+        // `Table::rename_columns`, because a column selected twice has the
+        // same name in both places and only one of them may be renamed.
+        let mut patterns = Vec::with_capacity(cli.columns.len());
+        let mut renames = Vec::new();
+        for column in &cli.columns {
+            match column.split_once('=') {
+                Some((new, old)) => {
+                    let old = old.trim();
+                    if old.contains(['*', '?']) {
+                        eprintln!(
+                            "tabx: error: Cannot rename '{}': the source must be a single column, not a wildcard",
+                            old
+                        );
+                        process::exit(EXIT_INVALID_ARGS);
+                    }
+                    renames.push((patterns.len(), new.trim()));
+                    patterns.push(old);
+                }
+                None => patterns.push(column.trim()),
+            }
+        }
+
+        let projected =
+            table
+                .select_matching_with_positions(&patterns)
+                .map(|(projected, positions)| {
+                    let (mut headers, rows) = projected.into_parts();
+                    for &(idx, new) in &renames {
+                        // Rename sources are literal, so they always have a position
+                        if let Some(position) = positions[idx] {
+                            headers[position] = new.to_string();
+                        }
+                    }
+                    Table::new(headers, rows)
+                });
+
+        match projected {
+            Ok(t) => t,
+            Err(e) => {
+                eprintln!("tabx: error: {}", e);
+                process::exit(EXIT_INVALID_ARGS);
+            }
+        }
+    };
+
+    let table = match cli.first_columns {
+        Some(n) => table.take_columns(n),
+        None => table,
+    };
+
+    // Tables without data rows keep all columns: there's nothing to judge them by
+    let table = if cli.drop_empty_columns && !table.is_empty() {
+        table.retain_columns_where(|_, values| values.iter().any(|v| !v.trim().is_empty()))
+    } else {
+        table
+    };
+
+    let table = match &cli.melt {
+        Some(ids) => {
+            let ids: Vec<&str> = ids.iter().map(|id| id.trim()).collect();
+            match table.melt(&ids, &cli.melt_var, &cli.melt_value) {
+                Ok(t) => t,
+                Err(e) => {
+                    eprintln!("tabx: error: {}", e);
+                    process::exit(EXIT_INVALID_ARGS);
+                }
+            }
+        }
+        None => table,
     };
 
+    let mut table = if cli.transpose {
+        table.transpose()
+    } else {
+        table
+    };
+
+    // Number rows last so the numbers reflect the final output order
+    if cli.row_numbers {
+        table.add_row_numbers("#");
+    }
+
     // Early delimiter conflict detection for TSV/custom delimiters
     // Check if output delimiter exists in data BEFORE writing
     // This provides fast feedback instead of failing after writing starts
@@ -232,64 +1575,180 @@ fn convert_table(cli: Cli) {
         Some(delimiter)
     } else if cli.output_format == "tsv" {
         Some('\t')
+    } else if cli.output_format == "psv" {
+        Some('|')
     } else {
         None // CSV handles escaping, no need to check
     };
 
+    let mut quote_delimiter = None;
     if let Some(delimiter) = output_delimiter {
-        // Check headers
-        for header in table.headers() {
-            if header.contains(delimiter) {
-                eprintln!(
-                    "tabx: error: Header '{}' contains delimiter character '{}'. Use -o csv for proper escaping.",
-                    header, delimiter
-                );
-                process::exit(EXIT_PARSE_ERROR);
-            }
-        }
-
-        // Check rows
-        for (idx, row) in table.rows().iter().enumerate() {
-            for cell in row {
-                if cell.contains(delimiter) {
+        let conflicts = find_delimiter_conflicts(&table, delimiter);
+        if !conflicts.is_empty() {
+            match cli.delimiter_conflict {
+                DelimiterConflict::Error => {
+                    for conflict in &conflicts {
+                        eprintln!("tabx: error: {}", conflict);
+                    }
                     eprintln!(
-                        "tabx: error: Row {} contains delimiter character '{}' in data. Use -o csv for proper escaping.",
-                        idx + 1, delimiter
+                        "tabx: error: Found delimiter character '{}' in {} place(s). Use -o csv for proper escaping.",
+                        delimiter.escape_default(),
+                        conflicts.len()
                     );
                     process::exit(EXIT_PARSE_ERROR);
                 }
+                DelimiterConflict::Quote => {
+                    eprintln!(
+                        "tabx: warning: Delimiter '{}' found in data; quoting fields CSV-style",
+                        delimiter.escape_default()
+                    );
+                    quote_delimiter = Some(delimiter);
+                }
+                DelimiterConflict::Strip => {
+                    let (headers, rows) = table.into_parts();
+                    let strip = |cell: String| cell.replace(delimiter, "");
+                    table = Table::new(
+                        headers.into_iter().map(strip).collect(),
+                        rows.into_iter()
+                            .map(|row| row.into_iter().map(strip).collect())
+                            .collect(),
+                    );
+                    eprintln!(
+                        "tabx: warning: Removed delimiter '{}' from the data",
+                        delimiter.escape_default()
+                    );
+                }
             }
         }
     }
 
     // Select the appropriate writer
+    let sink: Box<dyn Write> = match &cli.output {
+        Some(path) => match fs::File::create(path) {
+            Ok(file) => Box::new(file),
+            Err(e) => {
+                eprintln!("tabx: error: Cannot write {}: {}", path.display(), e);
+                process::exit(EXIT_IO_ERROR);
+            }
+        },
+        None => Box::new(io::stdout()),
+    };
     // Use BufWriter for 3-6x performance improvement on large outputs
-    let mut stdout = BufWriter::new(io::stdout());
-    let result = if let Some(delimiter) = cli.delimiter {
-        let writer = TsvWriter::new(delimiter);
-        writer.write(&table, &mut stdout)
+    let output = BufWriter::new(sink);
+    let mut output: Box<dyn Write> = match cli.flush_every {
+        Some(n) => Box::new(FlushEvery::new(output, n as usize)),
+        None => Box::new(output),
+    };
+
+    // Excel reads UTF-8 only when the file announces it with a byte order mark
+    if cli.bom {
+        if cli.output_format != "csv" {
+            eprintln!("tabx: warning: --bom only applies to csv output");
+        } else if let Err(e) = write!(output, "{}", BOM) {
+            exit_on_write_error(e.into());
+        }
+    }
+
+    // The comment convention only makes sense for line-based delimited output
+    if cli.emit_format_comment {
+        if cli.delimiter.is_some() {
+            eprintln!("tabx: warning: --emit-format-comment is ignored with a custom delimiter");
+        } else if !matches!(cli.output_format.as_str(), "tsv" | "csv" | "psv") {
+            eprintln!(
+                "tabx: warning: --emit-format-comment is ignored for {} output",
+                cli.output_format
+            );
+        } else if let Err(e) = writeln!(output, "{} {}", FORMAT_HINT_PREFIX, cli.output_format) {
+            exit_on_write_error(e.into());
+        }
+    }
+
+    // Comments would corrupt structured output, so only line-based formats get them
+    if cli.preserve_comments {
+        if cli.delimiter.is_none() && !matches!(cli.output_format.as_str(), "tsv" | "csv" | "psv") {
+            eprintln!(
+                "tabx: warning: --preserve-comments is ignored for {} output",
+                cli.output_format
+            );
+        } else {
+            for comment in comments {
+                if let Err(e) = writeln!(output, "{}", comment) {
+                    exit_on_write_error(e.into());
+                }
+            }
+        }
+    }
+
+    if cli.crlf && (cli.delimiter.is_some() || cli.output_format != "csv") {
+        eprintln!("tabx: warning: --crlf only applies to csv output");
+    }
+
+    // Only the line-based delimited writers have a header line to comment out
+    if cli.comment_header.is_some()
+        && cli.delimiter.is_none()
+        && !matches!(cli.output_format.as_str(), "tsv" | "csv" | "psv")
+    {
+        eprintln!(
+            "tabx: warning: --comment-header is ignored for {} output",
+            cli.output_format
+        );
+    }
+
+    // Delimiters are validated as ASCII, so the byte conversion is lossless
+    let result = if let Some(delimiter) = quote_delimiter {
+        let writer = csv_writer(cli, delimiter as u8);
+        writer.write(&table, &mut output)
+    } else if let Some(delimiter) = cli.delimiter {
+        let writer = tsv_writer(cli, delimiter);
+        writer.write(&table, &mut output)
     } else {
         match cli.output_format.as_str() {
+            "tsv" if cli.comment_header.is_none() => write_table(&table, Format::TSV, &mut output),
             "tsv" => {
-                let writer = TsvWriter::default();
-                writer.write(&table, &mut stdout)
+                let writer = tsv_writer(cli, '\t');
+                writer.write(&table, &mut output)
+            }
+            "csv" if cli.comment_header.is_none() && !cli.crlf => {
+                write_table(&table, Format::CSV, &mut output)
             }
             "csv" => {
-                let writer = CsvWriter::new();
-                writer.write(&table, &mut stdout)
+                let writer = csv_writer(cli, b',');
+                writer.write(&table, &mut output)
             }
-            _ => {
-                eprintln!(
-                    "tabx: error: Invalid output format '{}'. Valid formats: tsv, csv",
-                    cli.output_format
-                );
-                process::exit(EXIT_INVALID_ARGS);
+            "psv" if cli.comment_header.is_none() => write_table(&table, Format::Psv, &mut output),
+            "psv" => {
+                let writer = tsv_writer(cli, '|');
+                writer.write(&table, &mut output)
             }
+            "typed-tsv" => {
+                let writer = TypedTsvWriter::default();
+                writer.write(&table, &mut output)
+            }
+            "json" if !cli.pretty => write_table(&table, Format::Json, &mut output),
+            "json" => JsonWriter::pretty().write(&table, &mut output),
+            "ndjson" => NdJsonWriter.write(&table, &mut output),
+            "jsonl-typed" => JsonlTypedWriter.write(&table, &mut output),
+            "markdown" => write_table(&table, Format::Markdown, &mut output),
+            "mysql" => write_table(&table, Format::MySQL, &mut output),
+            "sql" => SqlWriter::new()
+                .table(&cli.sql_table)
+                .write(&table, &mut output),
+            "tree" => TreeWriter.write(&table, &mut output),
+            "debug" => write!(
+                output,
+                "format: {} ({})\n{}",
+                format,
+                origin.describe(),
+                table.to_debug_string()
+            )
+            .map_err(Into::into),
+            // main rejects names outside OUTPUT_FORMATS with validate_output_format
+            other => unreachable!("output format '{}' has no writer", other),
         }
     };
 
-    if let Err(e) = result {
-        eprintln!("tabx: error: {}", e);
-        process::exit(EXIT_IO_ERROR);
+    // Flush explicitly so errors writing the tail of a file aren't lost on drop
+    if let Err(e) = result.and_then(|_| output.flush().map_err(Into::into)) {
+        exit_on_write_error(e);
     }
 }
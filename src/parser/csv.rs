@@ -1,14 +1,19 @@
 use crate::error::Result;
-use crate::{Parser, Table};
-use csv::ReaderBuilder;
+use crate::parser::DelimitedParser;
+use crate::{Parser, RowStream, SkippedRow, StreamParser, Table};
 
+/// Parser for comma- or tab-separated input.
+///
+/// A thin preset over [`DelimitedParser`] that only varies the delimiter.
 pub struct CsvParser {
-    delimiter: u8,
+    inner: DelimitedParser,
 }
 
 impl CsvParser {
     pub fn new(delimiter: u8) -> Self {
-        Self { delimiter }
+        Self {
+            inner: DelimitedParser::new().delimiter(delimiter),
+        }
     }
 
     pub fn csv() -> Self {
@@ -20,31 +25,50 @@ impl CsvParser {
     }
 }
 
+impl CsvParser {
+    /// Parses the input, repairing rows that end with a spurious empty field.
+    ///
+    /// A trailing delimiter on each data line (`1,Alice,`) produces one more
+    /// field than the header has. When every data row has exactly one extra
+    /// field and that field is always empty, it is dropped. Returns the table
+    /// and whether the repair was applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is malformed CSV or if rows are still
+    /// inconsistent after the repair is considered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::parser::CsvParser;
+    ///
+    /// let parser = CsvParser::csv();
+    /// let (table, repaired) = parser
+    ///     .parse_trimming_trailing_empty_column("id,name\n1,Alice,\n2,Bob,")
+    ///     .unwrap();
+    ///
+    /// assert!(repaired);
+    /// assert_eq!(table.rows()[1], vec!["2", "Bob"]);
+    /// ```
+    pub fn parse_trimming_trailing_empty_column(&self, input: &str) -> Result<(Table, bool)> {
+        self.inner.parse_trimming_trailing_empty_column(input)
+    }
+}
+
+impl StreamParser for CsvParser {
+    fn parse_streaming<'a>(&self, input: &'a str) -> Result<(Vec<String>, RowStream<'a>)> {
+        self.inner.parse_streaming(input)
+    }
+}
+
 impl Parser for CsvParser {
     fn parse(&self, input: &str) -> Result<Table> {
-        let mut reader = ReaderBuilder::new()
-            .delimiter(self.delimiter)
-            .has_headers(true)
-            .from_reader(input.as_bytes());
-
-        // Get headers
-        let headers = reader
-            .headers()?
-            .iter()
-            .map(|s| s.to_string())
-            .collect::<Vec<_>>();
-
-        // Get rows with row number tracking for better error messages
-        let mut rows = Vec::new();
-        for (idx, result) in reader.records().enumerate() {
-            let record = result.map_err(|e| {
-                crate::error::Error::ParseError(format!("CSV row {}: {}", idx + 2, e))
-            })?;
-            let row = record.iter().map(|s| s.to_string()).collect();
-            rows.push(row);
-        }
+        self.inner.parse(input)
+    }
 
-        Table::new_validated(headers, rows)
+    fn parse_skipping_bad_rows(&self, input: &str) -> Result<(Table, Vec<SkippedRow>)> {
+        self.inner.parse_skipping_bad_rows(input)
     }
 }
 
@@ -80,6 +104,75 @@ mod tests {
         assert_eq!(table.rows()[1], vec!["2", "Bob"]);
     }
 
+    #[test]
+    fn test_parse_csv_strips_bom() {
+        let input = "\u{FEFF}id,name\n1,Alice";
+
+        let table = CsvParser::csv().parse(input).unwrap();
+
+        assert_eq!(table.headers(), &["id", "name"]);
+        assert_eq!(table.rows()[0], vec!["1", "Alice"]);
+    }
+
+    #[test]
+    fn test_parse_csv_strips_bom_before_quoted_header() {
+        let input = "\u{FEFF}\"id\",name\n1,Alice";
+
+        let table = CsvParser::csv().parse(input).unwrap();
+
+        assert_eq!(table.headers(), &["id", "name"]);
+    }
+
+    #[test]
+    fn test_trim_trailing_empty_column() {
+        let input = "id,name\n1,Alice,\n2,Bob,";
+
+        assert!(CsvParser::csv().parse(input).is_err());
+
+        let (table, repaired) = CsvParser::csv()
+            .parse_trimming_trailing_empty_column(input)
+            .unwrap();
+
+        assert!(repaired);
+        assert_eq!(table.column_count(), 2);
+        assert_eq!(table.rows()[0], vec!["1", "Alice"]);
+        assert_eq!(table.rows()[1], vec!["2", "Bob"]);
+    }
+
+    #[test]
+    fn test_trim_trailing_empty_column_only_when_always_empty() {
+        // The extra field holds data on one row, so it's not a spurious delimiter
+        let input = "id,name\n1,Alice,\n2,Bob,x";
+        assert!(CsvParser::csv()
+            .parse_trimming_trailing_empty_column(input)
+            .is_err());
+
+        // Consistent input is left untouched
+        let (table, repaired) = CsvParser::csv()
+            .parse_trimming_trailing_empty_column("id,name\n1,")
+            .unwrap();
+        assert!(!repaired);
+        assert_eq!(table.rows()[0], vec!["1", ""]);
+    }
+
+    #[test]
+    fn test_parse_skipping_bad_rows() {
+        // The quoted newline makes the bad row start on line 4, not row 3
+        let input = "id,note\n1,\"two\nlines\"\n2\n3,ok";
+        let (table, skipped) = CsvParser::csv().parse_skipping_bad_rows(input).unwrap();
+
+        assert_eq!(table.rows().len(), 2);
+        assert_eq!(table.rows()[1], vec!["3", "ok"]);
+        assert_eq!(
+            skipped,
+            vec![SkippedRow {
+                line: 4,
+                expected: 2,
+                found: 1
+            }]
+        );
+    }
+
     #[test]
     fn test_csv_error_includes_row_number() {
         // CSV with inconsistent field count on row 2 (first data row)
@@ -116,6 +209,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_csv_error_source_is_csv_error() {
+        use std::error::Error as _;
+
+        let err = CsvParser::csv().parse("id,name\n1,Alice\n2").unwrap_err();
+
+        let source = err
+            .source()
+            .expect("field-count error should keep its source");
+        assert!(source.downcast_ref::<csv::Error>().is_some());
+    }
+
     #[test]
     fn test_csv_error_includes_original_error() {
         // CSV with inconsistent field count
@@ -0,0 +1,344 @@
+use crate::error::Result;
+use crate::{
+    table_from_numbered_rows, NumberedRow, Parser, RowStream, SkippedRow, StreamParser, Table,
+};
+use csv::{Reader, ReaderBuilder, Trim};
+
+/// UTF-8 byte order mark, as Excel and other Windows tools write it
+pub const BOM: char = '\u{FEFF}';
+
+/// Configurable parser for delimiter-separated text.
+///
+/// Exposes the quoting, escaping and comment options of the underlying CSV
+/// reader. The defaults match RFC 4180 CSV: comma delimiter, `"` quotes with
+/// doubled `""` escapes, no comments and no trimming. A leading UTF-8 byte
+/// order mark is always ignored.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::parser::DelimitedParser;
+/// use table_extractor::Parser;
+///
+/// let parser = DelimitedParser::new()
+///     .delimiter(b';')
+///     .comment(Some(b'#'))
+///     .trim(true);
+///
+/// let table = parser.parse("# export\nid; name\n1; Alice").unwrap();
+/// assert_eq!(table.headers(), &["id", "name"]);
+/// assert_eq!(table.rows()[0], vec!["1", "Alice"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DelimitedParser {
+    delimiter: u8,
+    quote: u8,
+    escape: Option<u8>,
+    double_quote: bool,
+    flexible: bool,
+    comment: Option<u8>,
+    trim: bool,
+}
+
+impl DelimitedParser {
+    /// Creates a parser with RFC 4180 CSV defaults
+    pub fn new() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            escape: None,
+            double_quote: true,
+            flexible: false,
+            comment: None,
+            trim: false,
+        }
+    }
+
+    /// Sets the field delimiter
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the quote character
+    pub fn quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Sets an escape character for quotes inside quoted fields (e.g. `\\`)
+    pub fn escape(mut self, escape: Option<u8>) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    /// Sets whether a doubled quote (`""`) inside a quoted field is a literal quote
+    pub fn double_quote(mut self, double_quote: bool) -> Self {
+        self.double_quote = double_quote;
+        self
+    }
+
+    /// Lets the reader accept rows of differing lengths.
+    ///
+    /// The resulting table is still validated, so ragged rows are reported
+    /// as [`crate::error::Error::ParseErrorAt`] instead of a CSV error.
+    pub fn flexible(mut self, flexible: bool) -> Self {
+        self.flexible = flexible;
+        self
+    }
+
+    /// Sets a character that marks a line as a comment when it starts the line
+    pub fn comment(mut self, comment: Option<u8>) -> Self {
+        self.comment = comment;
+        self
+    }
+
+    /// Sets whether whitespace around headers and fields is trimmed
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Parses the input, repairing rows that end with a spurious empty field.
+    ///
+    /// A trailing delimiter on each data line (`1,Alice,`) produces one more
+    /// field than the header has. When every data row has exactly one extra
+    /// field and that field is always empty, it is dropped. Returns the table
+    /// and whether the repair was applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is malformed CSV or if rows are still
+    /// inconsistent after the repair is considered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::parser::DelimitedParser;
+    ///
+    /// let parser = DelimitedParser::new();
+    /// let (table, repaired) = parser
+    ///     .parse_trimming_trailing_empty_column("id,name\n1,Alice,\n2,Bob,")
+    ///     .unwrap();
+    ///
+    /// assert!(repaired);
+    /// assert_eq!(table.rows()[1], vec!["2", "Bob"]);
+    /// ```
+    pub fn parse_trimming_trailing_empty_column(&self, input: &str) -> Result<(Table, bool)> {
+        let (headers, mut rows) = self.read_records(input, true)?;
+
+        let width = headers.len();
+        let repair = !rows.is_empty()
+            && rows
+                .iter()
+                .all(|(_, row)| row.len() == width + 1 && row[width].is_empty());
+        if repair {
+            for (_, row) in &mut rows {
+                row.pop();
+            }
+        }
+
+        let (table, _) = table_from_numbered_rows(headers, rows, false)?;
+        Ok((table, repair))
+    }
+
+    /// Reads the header and data records, each paired with its line number,
+    /// optionally allowing ragged rows
+    fn read_records(&self, input: &str, flexible: bool) -> Result<(Vec<String>, Vec<NumberedRow>)> {
+        let mut reader = self.reader(input, flexible);
+
+        // Get headers
+        let headers = reader
+            .headers()?
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+
+        // Get rows with row number tracking for better error messages
+        let mut rows = Vec::new();
+        for (idx, result) in reader.records().enumerate() {
+            let record = result.map_err(|e| row_error(idx, e))?;
+            let line = record.position().map_or(idx + 2, |pos| pos.line() as usize);
+            let row = record.iter().map(|s| s.to_string()).collect();
+            rows.push((line, row));
+        }
+
+        Ok((headers, rows))
+    }
+
+    /// Builds a CSV reader over `input` with this parser's settings
+    fn reader<'a>(&self, input: &'a str, flexible: bool) -> Reader<&'a [u8]> {
+        // Excel and other Windows tools prefix UTF-8 files with a byte order mark,
+        // which would otherwise end up glued to the first header name
+        let input = input.strip_prefix(BOM).unwrap_or(input);
+
+        ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .escape(self.escape)
+            .double_quote(self.double_quote)
+            .comment(self.comment)
+            .trim(if self.trim { Trim::All } else { Trim::None })
+            .has_headers(true)
+            .flexible(flexible || self.flexible)
+            .from_reader(input.as_bytes())
+    }
+}
+
+/// Wraps a CSV error for the `idx`-th data record (the header is row 1)
+fn row_error(idx: usize, err: csv::Error) -> crate::error::Error {
+    crate::error::Error::ParseErrorWithSource {
+        message: format!("CSV row {}: {}", idx + 2, err),
+        source: Box::new(err),
+    }
+}
+
+impl Default for DelimitedParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StreamParser for DelimitedParser {
+    fn parse_streaming<'a>(&self, input: &'a str) -> Result<(Vec<String>, RowStream<'a>)> {
+        let mut reader = self.reader(input, false);
+        let headers: Vec<String> = reader.headers()?.iter().map(|s| s.to_string()).collect();
+
+        // A flexible reader lets ragged rows through, so check widths here
+        // as table_from_numbered_rows does for a whole table
+        let expected = headers.len();
+        let rows = reader.into_records().enumerate().map(move |(idx, result)| {
+            let record = result.map_err(|e| row_error(idx, e))?;
+            if record.len() != expected {
+                let err = crate::error::Error::InconsistentColumns {
+                    row: idx + 1,
+                    expected,
+                    found: record.len(),
+                };
+                return Err(crate::error::Error::ParseErrorAt {
+                    line: record.position().map_or(idx + 2, |pos| pos.line() as usize),
+                    message: err.to_string(),
+                });
+            }
+            Ok(record.iter().map(|s| s.to_string()).collect())
+        });
+
+        Ok((headers, Box::new(rows)))
+    }
+}
+
+impl Parser for DelimitedParser {
+    fn parse(&self, input: &str) -> Result<Table> {
+        let (headers, rows) = self.read_records(input, false)?;
+        table_from_numbered_rows(headers, rows, false).map(|(table, _)| table)
+    }
+
+    fn parse_skipping_bad_rows(&self, input: &str) -> Result<(Table, Vec<SkippedRow>)> {
+        let (headers, rows) = self.read_records(input, true)?;
+        table_from_numbered_rows(headers, rows, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_streaming_yields_rows_lazily() {
+        let input = "\u{FEFF}id;name\n1;Alice\n2\n3;Carol";
+        let (headers, mut rows) = DelimitedParser::new()
+            .delimiter(b';')
+            .parse_streaming(input)
+            .unwrap();
+
+        assert_eq!(headers, vec!["id", "name"]);
+        assert_eq!(rows.next().unwrap().unwrap(), vec!["1", "Alice"]);
+
+        let err = rows.next().unwrap().unwrap_err();
+        assert!(err.to_string().contains("CSV row 3"), "{}", err);
+        assert_eq!(rows.next().unwrap().unwrap(), vec!["3", "Carol"]);
+        assert!(rows.next().is_none());
+    }
+
+    #[test]
+    fn test_parse_streaming_flexible_reports_ragged_rows() {
+        let input = "id,name\n1,Alice\n2\n3,Carol,extra";
+        let (_, rows) = DelimitedParser::new()
+            .flexible(true)
+            .parse_streaming(input)
+            .unwrap();
+
+        let rows: Vec<_> = rows.collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].as_ref().unwrap(), &vec!["1", "Alice"]);
+        assert_eq!(
+            rows[1].as_ref().unwrap_err().to_string(),
+            "Line 3: Inconsistent column count at row 2: expected 2, found 1"
+        );
+        assert_eq!(
+            rows[2].as_ref().unwrap_err().to_string(),
+            "Line 4: Inconsistent column count at row 3: expected 2, found 3"
+        );
+    }
+
+    #[test]
+    fn test_backslash_escape() {
+        let input = "id,quote\n1,\"she said \\\"hi\\\"\"\n2,\"plain\"";
+        let parser = DelimitedParser::new()
+            .escape(Some(b'\\'))
+            .double_quote(false);
+
+        let table = parser.parse(input).unwrap();
+
+        assert_eq!(table.rows()[0], vec!["1", "she said \"hi\""]);
+        assert_eq!(table.rows()[1], vec!["2", "plain"]);
+    }
+
+    #[test]
+    fn test_no_double_quote() {
+        let input = "id,name\n1,\"a\"\"b\"";
+
+        let doubled = DelimitedParser::new().parse(input).unwrap();
+        assert_eq!(doubled.rows()[0], vec!["1", "a\"b"]);
+
+        // Without double-quote handling the field ends at the second quote and
+        // the remaining characters are kept as-is
+        let literal = DelimitedParser::new()
+            .double_quote(false)
+            .parse(input)
+            .unwrap();
+        assert_eq!(literal.rows()[0], vec!["1", "a\"b\""]);
+    }
+
+    #[test]
+    fn test_custom_quote_comment_and_trim() {
+        // Quotes only count at the start of a field, so none is padded here
+        let input = "# generated\nid | name\n1 |'Smith | Jones'";
+        let parser = DelimitedParser::new()
+            .delimiter(b'|')
+            .quote(b'\'')
+            .comment(Some(b'#'))
+            .trim(true);
+
+        let table = parser.parse(input).unwrap();
+
+        assert_eq!(table.headers(), &["id", "name"]);
+        assert_eq!(table.rows()[0], vec!["1", "Smith | Jones"]);
+    }
+
+    #[test]
+    fn test_flexible_reports_inconsistent_columns() {
+        let input = "a,b\n1,2\n3";
+
+        let err = DelimitedParser::new().parse(input).unwrap_err().to_string();
+        assert!(err.contains("CSV row 3"));
+
+        let err = DelimitedParser::new()
+            .flexible(true)
+            .parse(input)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Inconsistent column count at row 2"));
+        assert!(err.starts_with("Line 3: "), "{}", err);
+    }
+}
@@ -0,0 +1,298 @@
+use crate::error::{Error, Result};
+use crate::{Parser, Table};
+use regex::Regex;
+use std::sync::OnceLock;
+
+static TAG: OnceLock<Regex> = OnceLock::new();
+static INNER_TAG: OnceLock<Regex> = OnceLock::new();
+static ENTITY: OnceLock<Regex> = OnceLock::new();
+
+fn get_tag() -> &'static Regex {
+    TAG.get_or_init(|| {
+        Regex::new(r"(?is)<(/?)(table|tr|th|td)\b[^>]*>").expect("Invalid HTML tag regex")
+    })
+}
+
+fn get_inner_tag() -> &'static Regex {
+    INNER_TAG.get_or_init(|| Regex::new(r"(?s)<[^>]*>").expect("Invalid HTML inner tag regex"))
+}
+
+fn get_entity() -> &'static Regex {
+    ENTITY.get_or_init(|| {
+        Regex::new(r"&(#[0-9]+|#[xX][0-9a-fA-F]+|[a-zA-Z]+);").expect("Invalid HTML entity regex")
+    })
+}
+
+/// Which `<table>` of a page [`HtmlParser`] reads
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableSelection {
+    /// The table at this 0-based position, in document order
+    Index(usize),
+
+    /// The first table whose header row includes all of these names
+    WithHeader(Vec<String>),
+}
+
+/// Parser for `<table>` elements in an HTML page.
+///
+/// The first row of the selected table is the header, whether it uses `<th>`
+/// or `<td>` cells. Cell text has inner tags removed, common entities decoded
+/// and whitespace collapsed. Tables nested inside a cell are parsed as
+/// separate tables, counted in the order they open. `colspan` and `rowspan`
+/// are not expanded.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::parser::HtmlParser;
+/// use table_extractor::Parser;
+///
+/// let html = "<table><tr><td>nav</td></tr></table>
+///     <table><tr><th>id</th><th>name</th></tr><tr><td>1</td><td>A &amp; B</td></tr></table>";
+///
+/// let table = HtmlParser::new().with_header(&["name"]).parse(html).unwrap();
+/// assert_eq!(table.headers(), &["id", "name"]);
+/// assert_eq!(table.rows()[0], vec!["1", "A & B"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtmlParser {
+    selection: TableSelection,
+}
+
+impl HtmlParser {
+    /// Creates a parser reading the first table on the page
+    pub fn new() -> Self {
+        Self {
+            selection: TableSelection::Index(0),
+        }
+    }
+
+    /// Reads the table at this 0-based position instead of the first
+    pub fn table_index(mut self, index: usize) -> Self {
+        self.selection = TableSelection::Index(index);
+        self
+    }
+
+    /// Reads the first table whose header row includes all of `names`
+    pub fn with_header(mut self, names: &[&str]) -> Self {
+        self.selection =
+            TableSelection::WithHeader(names.iter().map(|name| name.to_string()).collect());
+        self
+    }
+}
+
+impl Default for HtmlParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parser for HtmlParser {
+    fn parse(&self, input: &str) -> Result<Table> {
+        let tables = extract_tables(input);
+        if tables.is_empty() {
+            return Err(Error::ParseError("No <table> element found".to_string()));
+        }
+
+        let rows = match &self.selection {
+            TableSelection::Index(index) => tables.get(*index).ok_or_else(|| {
+                Error::ParseError(format!(
+                    "Table index {} is out of range: found {} tables",
+                    index,
+                    tables.len()
+                ))
+            })?,
+            TableSelection::WithHeader(names) => tables
+                .iter()
+                .find(|rows| {
+                    rows.first()
+                        .is_some_and(|header| names.iter().all(|name| header.contains(name)))
+                })
+                .ok_or_else(|| {
+                    Error::ParseError(format!("No table has the headers: {}", names.join(", ")))
+                })?,
+        };
+
+        let mut rows = rows.clone().into_iter();
+        let headers = rows.next().unwrap_or_default();
+        Table::new_validated(headers, rows.collect())
+    }
+}
+
+/// A table under construction: its completed rows plus the open row and cell
+#[derive(Default)]
+struct TableBuilder {
+    slot: usize,
+    rows: Vec<Vec<String>>,
+    row: Option<Vec<String>>,
+    cell_start: Option<usize>,
+}
+
+impl TableBuilder {
+    fn close_cell(&mut self, input: &str, end: usize) {
+        if let Some(start) = self.cell_start.take() {
+            let text = cell_text(&input[start..end]);
+            self.row.get_or_insert_with(Vec::new).push(text);
+        }
+    }
+
+    fn close_row(&mut self, input: &str, end: usize) {
+        self.close_cell(input, end);
+        if let Some(row) = self.row.take() {
+            if !row.is_empty() {
+                self.rows.push(row);
+            }
+        }
+    }
+}
+
+/// Returns the rows of every table in the document, in the order the tables open
+fn extract_tables(input: &str) -> Vec<Vec<Vec<String>>> {
+    let mut tables: Vec<Vec<Vec<String>>> = Vec::new();
+    let mut open: Vec<TableBuilder> = Vec::new();
+
+    for tag in get_tag().captures_iter(input) {
+        let whole = tag.get(0).expect("capture 0 always exists");
+        let closing = !tag[1].is_empty();
+        let name = tag[2].to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("table", false) => {
+                // Text of an enclosing cell stops where the nested table starts
+                if let Some(outer) = open.last_mut() {
+                    outer.close_cell(input, whole.start());
+                }
+                tables.push(Vec::new());
+                open.push(TableBuilder {
+                    slot: tables.len() - 1,
+                    ..TableBuilder::default()
+                });
+            }
+            ("table", true) => {
+                if let Some(mut table) = open.pop() {
+                    table.close_row(input, whole.start());
+                    tables[table.slot] = table.rows;
+                }
+            }
+            _ => {
+                let Some(table) = open.last_mut() else {
+                    continue;
+                };
+                match (name.as_str(), closing) {
+                    ("tr", false) => {
+                        table.close_row(input, whole.start());
+                        table.row = Some(Vec::new());
+                    }
+                    ("tr", true) => table.close_row(input, whole.start()),
+                    (_, false) => {
+                        table.close_cell(input, whole.start());
+                        table.cell_start = Some(whole.end());
+                    }
+                    (_, true) => table.close_cell(input, whole.start()),
+                }
+            }
+        }
+    }
+
+    // Unclosed tables still yield what was read
+    while let Some(mut table) = open.pop() {
+        table.close_row(input, input.len());
+        tables[table.slot] = table.rows;
+    }
+
+    tables
+}
+
+/// Strips tags, decodes entities and collapses whitespace in a cell's markup
+fn cell_text(markup: &str) -> String {
+    let text = get_inner_tag().replace_all(markup, " ");
+    let text = get_entity().replace_all(&text, |caps: &regex::Captures| {
+        decode_entity(&caps[1]).unwrap_or_else(|| caps[0].to_string())
+    });
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn decode_entity(entity: &str) -> Option<String> {
+    let c = match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        _ => {
+            let code = entity.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(code)?
+        }
+    };
+    Some(c.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body>
+<table class="layout"><tr><td>Menu</td><td>Home</td></tr></table>
+<p>Report</p>
+<TABLE>
+  <thead><tr><th>id</th><th>Name</th></tr></thead>
+  <tbody>
+    <tr><td>1</td><td><b>Alice</b> &lt;admin&gt;</td></tr>
+    <tr><td>2</td><td>Bob&#39;s&nbsp;<i>team</i></td></tr>
+  </tbody>
+</TABLE>
+</body></html>"#;
+
+    #[test]
+    fn test_select_table_by_index() {
+        let first = HtmlParser::new().parse(PAGE).unwrap();
+        assert_eq!(first.headers(), &["Menu", "Home"]);
+        assert!(first.is_empty());
+
+        let second = HtmlParser::new().table_index(1).parse(PAGE).unwrap();
+        assert_eq!(second.headers(), &["id", "Name"]);
+        assert_eq!(second.rows()[0], vec!["1", "Alice <admin>"]);
+        assert_eq!(second.rows()[1], vec!["2", "Bob's team"]);
+    }
+
+    #[test]
+    fn test_select_table_by_header() {
+        let table = HtmlParser::new()
+            .with_header(&["Name", "id"])
+            .parse(PAGE)
+            .unwrap();
+        assert_eq!(table.rows().len(), 2);
+
+        let err = HtmlParser::new()
+            .with_header(&["email"])
+            .parse(PAGE)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "No table has the headers: email");
+    }
+
+    #[test]
+    fn test_index_out_of_range() {
+        let err = HtmlParser::new().table_index(5).parse(PAGE).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Table index 5 is out of range: found 2 tables"
+        );
+        assert!(HtmlParser::new().parse("<p>no tables</p>").is_err());
+    }
+
+    #[test]
+    fn test_nested_tables_are_separate() {
+        let html = "<table><tr><td>outer<table><tr><td>inner</td></tr></table></td></tr></table>";
+
+        let outer = HtmlParser::new().parse(html).unwrap();
+        let inner = HtmlParser::new().table_index(1).parse(html).unwrap();
+
+        assert_eq!(outer.headers(), &["outer"]);
+        assert_eq!(inner.headers(), &["inner"]);
+    }
+}
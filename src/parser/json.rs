@@ -0,0 +1,232 @@
+use crate::error::{Error, Result};
+use crate::parser::ndjson::{expect, parse_value, read_object, skip_whitespace};
+use crate::{Parser, Table};
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Parser for a JSON array of rows, as returned by many HTTP APIs.
+///
+/// Two layouts are accepted:
+///
+/// - An array of objects, `[{"id":1,"name":"Alice"}]`. The header is the
+///   union of keys across all objects, in first-seen order, and keys missing
+///   from an object give empty cells.
+/// - An array of arrays, `[["id","name"],[1,"Alice"]]`. The first inner array
+///   is the header, and every other one must have as many values.
+///
+/// Values are read as in [`NdjsonParser`](crate::parser::NdjsonParser):
+/// strings are unescaped, numbers and booleans keep their literal text,
+/// `null` becomes an empty cell, and nested objects and arrays are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::parser::JsonParser;
+/// use table_extractor::Parser;
+///
+/// let table = JsonParser.parse(r#"[["id","name"],[1,"Alice"]]"#).unwrap();
+/// assert_eq!(table.headers(), &["id", "name"]);
+/// assert_eq!(table.rows()[0], vec!["1", "Alice"]);
+///
+/// let table = JsonParser.parse(r#"[{"id":1},{"id":2,"admin":true}]"#).unwrap();
+/// assert_eq!(table.headers(), &["id", "admin"]);
+/// assert_eq!(table.rows()[1], vec!["2", "true"]);
+/// ```
+pub struct JsonParser;
+
+impl Parser for JsonParser {
+    fn parse(&self, input: &str) -> Result<Table> {
+        let table =
+            parse_document(input).map_err(|msg| Error::ParseError(format!("JSON: {}", msg)))?;
+        table.validate()?;
+        Ok(table)
+    }
+}
+
+fn parse_document(input: &str) -> std::result::Result<Table, String> {
+    let mut chars = input.chars().peekable();
+
+    skip_whitespace(&mut chars);
+    expect(&mut chars, '[')?;
+    skip_whitespace(&mut chars);
+
+    let table = match chars.peek() {
+        Some(']') => {
+            chars.next();
+            Table::new(vec![], vec![])
+        }
+        Some('{') => read_objects(&mut chars)?,
+        Some('[') => read_arrays(&mut chars)?,
+        Some(_) => return Err("expected an array of objects or an array of arrays".to_string()),
+        None => return Err("unterminated array".to_string()),
+    };
+
+    skip_whitespace(&mut chars);
+    match chars.next() {
+        None => Ok(table),
+        Some(c) => Err(format!("unexpected '{}' after array", c)),
+    }
+}
+
+/// Reads the elements of the outer array as objects, through its closing `]`
+fn read_objects(chars: &mut Peekable<Chars>) -> std::result::Result<Table, String> {
+    let mut headers = Vec::new();
+    let mut columns: HashMap<String, usize> = HashMap::new();
+    let mut records = Vec::new();
+
+    read_elements(chars, '{', |chars| {
+        let fields = read_object(chars)?;
+        for (key, _) in &fields {
+            if !columns.contains_key(key) {
+                columns.insert(key.clone(), headers.len());
+                headers.push(key.clone());
+            }
+        }
+        records.push(fields);
+        Ok(())
+    })?;
+
+    // The header is only complete once every object has been read
+    let rows = records
+        .into_iter()
+        .map(|fields| {
+            let mut row = vec![String::new(); headers.len()];
+            for (key, value) in fields {
+                row[columns[&key]] = value;
+            }
+            row
+        })
+        .collect();
+
+    Ok(Table::new(headers, rows))
+}
+
+/// Reads the elements of the outer array as arrays, through its closing `]`
+fn read_arrays(chars: &mut Peekable<Chars>) -> std::result::Result<Table, String> {
+    let mut arrays = Vec::new();
+    read_elements(chars, '[', |chars| {
+        arrays.push(read_array(chars)?);
+        Ok(())
+    })?;
+
+    let mut arrays = arrays.into_iter();
+    let headers = arrays.next().unwrap_or_default();
+    Ok(Table::new(headers, arrays.collect()))
+}
+
+/// Calls `read` for each element of the outer array, which must all start
+/// with the `open` bracket
+fn read_elements<F>(
+    chars: &mut Peekable<Chars>,
+    open: char,
+    mut read: F,
+) -> std::result::Result<(), String>
+where
+    F: FnMut(&mut Peekable<Chars>) -> std::result::Result<(), String>,
+{
+    let kind = if open == '{' { "an object" } else { "an array" };
+    for idx in 1.. {
+        skip_whitespace(chars);
+        if chars.peek() != Some(&open) {
+            return Err(format!("element {} is not {}", idx, kind));
+        }
+        read(chars)?;
+
+        skip_whitespace(chars);
+        match chars.next() {
+            Some(',') => continue,
+            Some(']') => break,
+            Some(c) => return Err(format!("expected ',' or ']', found '{}'", c)),
+            None => return Err("unterminated array".to_string()),
+        }
+    }
+    Ok(())
+}
+
+/// Reads a flat JSON array, from its opening `[` to its closing `]`
+fn read_array(chars: &mut Peekable<Chars>) -> std::result::Result<Vec<String>, String> {
+    let mut values = Vec::new();
+
+    expect(chars, '[')?;
+    skip_whitespace(chars);
+
+    if chars.peek() == Some(&']') {
+        chars.next();
+        return Ok(values);
+    }
+
+    loop {
+        skip_whitespace(chars);
+        values.push(parse_value(chars)?);
+
+        skip_whitespace(chars);
+        match chars.next() {
+            Some(',') => continue,
+            Some(']') => return Ok(values),
+            Some(c) => return Err(format!("expected ',' or ']', found '{}'", c)),
+            None => return Err("unterminated array".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_array_of_objects() {
+        let input = r#"[
+  {"id": 1, "name": "Alice", "score": 9.5},
+  {"id": 2, "active": false, "name": "Bob \"B\""},
+  {"name": null, "id": 3}
+]"#;
+
+        let table = JsonParser.parse(input).unwrap();
+
+        assert_eq!(table.headers(), &["id", "name", "score", "active"]);
+        assert_eq!(table.rows().len(), 3);
+        assert_eq!(table.rows()[0], vec!["1", "Alice", "9.5", ""]);
+        assert_eq!(table.rows()[1], vec!["2", "Bob \"B\"", "", "false"]);
+        assert_eq!(table.rows()[2], vec!["3", "", "", ""]);
+    }
+
+    #[test]
+    fn test_parse_array_of_arrays() {
+        let input = "[[\"id\",\"name\",\"ok\"],\n [1, \"Zo\\u00eb\", true],\n [2, \"Bob\", null]]";
+
+        let table = JsonParser.parse(input).unwrap();
+
+        assert_eq!(table.headers(), &["id", "name", "ok"]);
+        assert_eq!(table.rows()[0], vec!["1", "Zoë", "true"]);
+        assert_eq!(table.rows()[1], vec!["2", "Bob", ""]);
+
+        let header_only = JsonParser.parse("[[\"id\"]]").unwrap();
+        assert_eq!(header_only.headers(), &["id"]);
+        assert!(header_only.is_empty());
+        assert_eq!(JsonParser.parse(" [ ] ").unwrap().column_count(), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for (input, message) in [
+            ("[[\"a\",\"b\"],[1]]", "at row 1: expected 2, found 1"),
+            ("[{\"a\":1},[1]]", "JSON: element 2 is not an object"),
+            ("[[\"a\"],{\"a\":1}]", "JSON: element 2 is not an array"),
+            (
+                "[1, 2]",
+                "JSON: expected an array of objects or an array of arrays",
+            ),
+            (
+                "[[\"a\"],[[1]]]",
+                "JSON: nested objects and arrays are not supported",
+            ),
+            ("[[\"a\"]] x", "JSON: unexpected 'x' after array"),
+            ("[[\"a\"]", "JSON: unterminated array"),
+            ("{\"a\":1}", "JSON: expected '[', found '{'"),
+        ] {
+            let err = JsonParser.parse(input).unwrap_err().to_string();
+            assert!(err.contains(message), "{}: {}", input, err);
+        }
+    }
+}
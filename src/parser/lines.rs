@@ -0,0 +1,41 @@
+use crate::error::Result;
+use crate::{Parser, Table};
+
+/// Header given to the single column produced by [`LinesParser`]
+pub const LINES_HEADER: &str = "value";
+
+/// Parser for plain text with one value per line.
+///
+/// Each non-blank line becomes a single-cell row under a synthetic
+/// [`LINES_HEADER`] column; the text is kept as-is, delimiters included.
+pub struct LinesParser;
+
+impl Parser for LinesParser {
+    fn parse(&self, input: &str) -> Result<Table> {
+        let rows = input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| vec![line.to_string()])
+            .collect();
+
+        Ok(Table::new(vec![LINES_HEADER.to_string()], rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        let table = LinesParser
+            .parse("apple\nbanana, ripe\n\ncherry\n")
+            .unwrap();
+
+        assert_eq!(table.headers(), &["value"]);
+        assert_eq!(table.rows().len(), 3);
+        assert_eq!(table.rows()[0], vec!["apple"]);
+        assert_eq!(table.rows()[1], vec!["banana, ripe"]);
+        assert_eq!(table.rows()[2], vec!["cherry"]);
+    }
+}
@@ -1,48 +1,96 @@
 use crate::error::Result;
-use crate::{Parser, Table};
+use crate::{table_from_numbered_rows, Parser, SkippedRow, Table};
 
 pub struct MarkdownParser;
 
 impl Parser for MarkdownParser {
     fn parse(&self, input: &str) -> Result<Table> {
-        let lines: Vec<&str> = input.lines().collect();
+        parse_rows(input, false).map(|(table, _)| table)
+    }
 
-        if lines.is_empty() {
-            return Ok(Table::new(vec![], vec![]));
-        }
+    fn parse_skipping_bad_rows(&self, input: &str) -> Result<(Table, Vec<SkippedRow>)> {
+        parse_rows(input, true)
+    }
+}
 
-        let mut headers = Vec::new();
-        let mut rows = Vec::new();
-        let mut found_separator = false;
+fn parse_rows(input: &str, skip_bad_rows: bool) -> Result<(Table, Vec<SkippedRow>)> {
+    let lines: Vec<&str> = input.lines().collect();
 
-        for line in lines {
-            let trimmed = line.trim();
+    if lines.is_empty() {
+        return Ok((Table::new(vec![], vec![]), Vec::new()));
+    }
 
-            // Skip empty lines
-            if trimmed.is_empty() {
-                continue;
-            }
+    let mut headers = Vec::new();
+    let mut rows = Vec::new();
+    let mut found_separator = false;
 
-            // Check if this is a separator line (contains only |, -, :, and whitespace)
-            if is_separator_line(trimmed) {
-                found_separator = true;
-                continue;
-            }
+    for (idx, line) in lines.into_iter().enumerate() {
+        let trimmed = line.trim();
 
-            // Parse the row
-            let cells = parse_markdown_row(trimmed);
+        // Skip empty lines
+        if trimmed.is_empty() {
+            continue;
+        }
 
-            if !found_separator && headers.is_empty() {
-                // First row is the header
-                headers = cells;
-            } else if found_separator {
-                // Data rows come after the separator
-                rows.push(cells);
-            }
+        // Check if this is a separator line (contains only |, -, :, and whitespace)
+        if is_separator_line(trimmed) {
+            found_separator = true;
+            continue;
         }
 
-        Table::new_validated(headers, rows)
+        // Parse the row
+        let cells = parse_markdown_row(trimmed);
+
+        if !found_separator && headers.is_empty() {
+            // First row is the header
+            headers = cells;
+        } else if found_separator {
+            // Data rows come after the separator
+            rows.push((idx + 1, cells));
+        }
     }
+
+    table_from_numbered_rows(headers, rows, skip_bad_rows)
+}
+
+/// Removes bullet list markers (`- `, `* ` or `+ `) from table lines.
+///
+/// Tables copied out of a bulleted list keep the marker on every line, which
+/// hides the leading pipe. A marker is only removed when it's followed by
+/// whitespace and then a `|`, so separator lines such as `--- | ---` are
+/// never touched.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::parser::markdown::strip_list_markers;
+///
+/// let input = "- | id | name |\n- |----|------|\n- | 1  | Alice |";
+/// assert_eq!(
+///     strip_list_markers(input),
+///     "| id | name |\n|----|------|\n| 1  | Alice |"
+/// );
+/// ```
+pub fn strip_list_markers(input: &str) -> String {
+    input
+        .lines()
+        .map(|line| {
+            let rest = line.trim_start();
+            let mut chars = rest.chars();
+            match (chars.next(), chars.next()) {
+                (Some('-' | '*' | '+'), Some(c)) if c.is_whitespace() => {
+                    let cells = rest[1..].trim_start();
+                    if cells.starts_with('|') {
+                        cells
+                    } else {
+                        line
+                    }
+                }
+                _ => line,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 fn is_separator_line(line: &str) -> bool {
@@ -53,26 +101,88 @@ fn is_separator_line(line: &str) -> bool {
 }
 
 fn parse_markdown_row(line: &str) -> Vec<String> {
-    // Remove leading and trailing pipes
-    let trimmed = line.trim().trim_start_matches('|').trim_end_matches('|');
+    // Remove leading and trailing pipes, keeping an escaped trailing `\|`
+    let mut trimmed = line.trim().trim_start_matches('|');
+    while trimmed.ends_with('|') && !trimmed.ends_with("\\|") {
+        trimmed = &trimmed[..trimmed.len() - 1];
+    }
+
+    // An unclosed backtick is literal, so fall back to splitting on every pipe
+    split_cells(trimmed, true).unwrap_or_else(|| {
+        split_cells(trimmed, false).expect("splitting without code spans always succeeds")
+    })
+}
 
+/// Splits a row on unescaped `|` characters and trims each cell.
+///
+/// `\|` is unescaped to a literal pipe. When `code_spans` is set, pipes
+/// inside backtick code spans don't split cells; a span closes on a backtick
+/// run of the same length that opened it. Returns `None` if a code span is
+/// left unclosed.
+fn split_cells(content: &str, code_spans: bool) -> Option<Vec<String>> {
     // Estimate column count for pre-allocation
-    let estimated_cols = trimmed.chars().filter(|&c| c == '|').count() + 1;
+    let estimated_cols = content.chars().filter(|&c| c == '|').count() + 1;
     let mut cells = Vec::with_capacity(estimated_cols);
 
-    // Split by | and trim each cell
-    // Only allocate new string if trimming changes the value
-    for cell in trimmed.split('|') {
-        cells.push(cell.trim().to_string());
+    let mut current = String::new();
+    // Length of the backtick run that opened the current code span (0 = none)
+    let mut open_ticks = 0;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                chars.next();
+                current.push('|');
+            }
+            '`' if code_spans => {
+                let mut run = 1;
+                while chars.next_if_eq(&'`').is_some() {
+                    run += 1;
+                }
+                if open_ticks == 0 {
+                    open_ticks = run;
+                } else if run == open_ticks {
+                    open_ticks = 0;
+                }
+                current.extend(std::iter::repeat_n('`', run));
+            }
+            '|' if open_ticks == 0 => {
+                cells.push(current.trim().to_string());
+                current.clear();
+            }
+            _ => current.push(c),
+        }
     }
 
-    cells
+    if open_ticks != 0 {
+        return None;
+    }
+
+    cells.push(current.trim().to_string());
+    Some(cells)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_strip_list_markers() {
+        let input = "  * | id | name |\n  * |----|------|\n  * | 1 | Alice |\n  + | 2 | Bob |";
+        let stripped = strip_list_markers(input);
+
+        let table = MarkdownParser.parse(&stripped).unwrap();
+        assert_eq!(table.headers(), &["id", "name"]);
+        assert_eq!(table.rows(), &[vec!["1", "Alice"], vec!["2", "Bob"]]);
+    }
+
+    #[test]
+    fn test_strip_list_markers_keeps_separators_and_text() {
+        let input = "--- | ---\n- not a table row\n-|x|";
+        assert_eq!(strip_list_markers(input), input);
+    }
+
     #[test]
     fn test_parse_markdown() {
         let input = r#"| API Metric Name | MongoDB Slice | Position |
@@ -91,4 +201,60 @@ mod tests {
         assert_eq!(table.rows()[0], vec!["sessions", "ACQUISITION", "Index 0"]);
         assert_eq!(table.rows()[1], vec!["newUsers", "ACQUISITION", "Index 1"]);
     }
+
+    #[test]
+    fn test_header_and_separator_only() {
+        let table = MarkdownParser
+            .parse("| id | name | email |\n|----|------|-------|\n")
+            .unwrap();
+
+        assert_eq!(table.column_count(), 3);
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn test_trailing_separator_lines_are_ignored() {
+        let input = "| id | name |\n|----|------|\n| 1 | Alice |\n|----|------|\n|---|---|";
+
+        let table = MarkdownParser.parse(input).unwrap();
+
+        assert_eq!(table.headers(), &["id", "name"]);
+        assert_eq!(table.rows(), &[vec!["1", "Alice"]]);
+    }
+
+    #[test]
+    fn test_row_error_names_line() {
+        let input = "| id | name |\n|----|------|\n\n| 1 | Alice |\n| 2 |";
+
+        let err = MarkdownParser.parse(input).unwrap_err();
+
+        assert!(matches!(
+            err,
+            crate::error::Error::ParseErrorAt { line: 5, .. }
+        ));
+        assert!(err.to_string().starts_with("Line 5: "), "{}", err);
+    }
+
+    #[test]
+    fn test_pipe_inside_code_span() {
+        let input = "| id | expr |\n|----|------|\n| 1 | `x|y` |\n| 2 | ``a`|`b`` |";
+
+        let parser = MarkdownParser;
+        let table = parser.parse(input).unwrap();
+
+        assert_eq!(table.column_count(), 2);
+        assert_eq!(table.rows()[0], vec!["1", "`x|y`"]);
+        assert_eq!(table.rows()[1], vec!["2", "``a`|`b``"]);
+    }
+
+    #[test]
+    fn test_escaped_pipe() {
+        assert_eq!(parse_markdown_row("| a \\| b | c |"), vec!["a | b", "c"]);
+        assert_eq!(parse_markdown_row("| a | b \\|"), vec!["a", "b |"]);
+    }
+
+    #[test]
+    fn test_unclosed_backtick_is_literal() {
+        assert_eq!(parse_markdown_row("| it`s | b |"), vec!["it`s", "b"]);
+    }
 }
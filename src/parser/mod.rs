@@ -1,9 +1,47 @@
 pub mod csv;
+pub mod delimited;
+pub mod html;
+pub mod json;
+pub mod lines;
 pub mod markdown;
 pub mod mysql;
+pub mod ndjson;
+pub mod org;
 pub mod postgres;
 
 pub use self::csv::CsvParser;
+pub use delimited::DelimitedParser;
+pub use html::HtmlParser;
+pub use json::JsonParser;
+pub use lines::LinesParser;
 pub use markdown::MarkdownParser;
 pub use mysql::MySqlParser;
+pub use ndjson::NdjsonParser;
+pub use org::OrgParser;
 pub use postgres::PostgresParser;
+
+/// Trims a cell and replaces tabs used as alignment padding with spaces,
+/// so they don't leak into the output and conflict with the TSV delimiter
+pub(crate) fn normalize_cell(cell: &str) -> String {
+    let trimmed = cell.trim();
+    if trimmed.contains('\t') {
+        trimmed.replace('\t', " ")
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Splits a bordered `| a | b |` row into normalized cells.
+///
+/// Exactly one border pipe is removed per side, so `||` at either end is an
+/// empty edge cell rather than a doubled border.
+pub(crate) fn split_pipe_row(line: &str) -> Vec<String> {
+    let trimmed = line.trim();
+    let inner = trimmed.strip_prefix('|').unwrap_or(trimmed);
+    let inner = inner.strip_suffix('|').unwrap_or(inner);
+
+    // Pre-allocation reduces allocations for large tables
+    let mut cells = Vec::with_capacity(inner.matches('|').count() + 1);
+    cells.extend(inner.split('|').map(normalize_cell));
+    cells
+}
@@ -1,63 +1,56 @@
 use crate::error::Result;
-use crate::{Parser, Table};
+use crate::parser::split_pipe_row;
+use crate::{table_from_numbered_rows, Parser, SkippedRow, Table};
 
 pub struct MySqlParser;
 
 impl Parser for MySqlParser {
     fn parse(&self, input: &str) -> Result<Table> {
-        let lines: Vec<&str> = input.lines().collect();
+        parse_rows(input, false).map(|(table, _)| table)
+    }
 
-        if lines.is_empty() {
-            return Ok(Table::new(vec![], vec![]));
-        }
+    fn parse_skipping_bad_rows(&self, input: &str) -> Result<(Table, Vec<SkippedRow>)> {
+        parse_rows(input, true)
+    }
+}
 
-        let mut headers = Vec::new();
-        let mut rows = Vec::new();
+fn parse_rows(input: &str, skip_bad_rows: bool) -> Result<(Table, Vec<SkippedRow>)> {
+    let lines: Vec<&str> = input.lines().collect();
 
-        for line in lines {
-            let trimmed = line.trim();
+    if lines.is_empty() {
+        return Ok((Table::new(vec![], vec![]), Vec::new()));
+    }
 
-            // Skip empty lines and border lines (starting with +)
-            if trimmed.is_empty() || trimmed.starts_with('+') {
-                continue;
-            }
+    let mut headers = Vec::new();
+    let mut rows = Vec::new();
 
-            // Parse data lines (starting and ending with |)
-            if trimmed.starts_with('|') && trimmed.ends_with('|') {
-                let cells = parse_mysql_row(trimmed);
+    for (idx, line) in lines.into_iter().enumerate() {
+        let trimmed = line.trim();
 
-                if headers.is_empty() {
-                    headers = cells;
-                } else {
-                    rows.push(cells);
-                }
-            }
+        // Skip empty lines and border lines (starting with +)
+        if trimmed.is_empty() || trimmed.starts_with('+') {
+            continue;
         }
 
-        Table::new_validated(headers, rows)
-    }
-}
-
-fn parse_mysql_row(line: &str) -> Vec<String> {
-    // Remove leading and trailing pipes
-    let trimmed = line.trim().trim_start_matches('|').trim_end_matches('|');
+        // Parse data lines (starting and ending with |)
+        if trimmed.starts_with('|') && trimmed.ends_with('|') {
+            let cells = split_pipe_row(trimmed);
 
-    // Estimate column count for pre-allocation
-    let estimated_cols = trimmed.chars().filter(|&c| c == '|').count() + 1;
-    let mut cells = Vec::with_capacity(estimated_cols);
-
-    // Split by | and trim each cell
-    // Pre-allocation reduces allocations for large tables
-    for cell in trimmed.split('|') {
-        cells.push(cell.trim().to_string());
+            if headers.is_empty() {
+                headers = cells;
+            } else {
+                rows.push((idx + 1, cells));
+            }
+        }
     }
 
-    cells
+    table_from_numbered_rows(headers, rows, skip_bad_rows)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::Error;
 
     #[test]
     fn test_parse_mysql() {
@@ -76,4 +69,48 @@ mod tests {
         assert_eq!(table.rows()[0], vec!["1", "Preston Carlton's Company"]);
         assert_eq!(table.rows()[1], vec!["2", "Fawzia Masud's Company"]);
     }
+
+    #[test]
+    fn test_parse_mysql_keeps_empty_edge_cells() {
+        let input = "+----+------+-------+
+| id | name | email |
++----+------+-------+
+|  1 | Bob  |       |
+|  2 | Ann  ||
+||      | a@b.c |
++----+------+-------+";
+
+        let table = MySqlParser.parse(input).unwrap();
+
+        assert_eq!(table.rows().len(), 3);
+        assert_eq!(table.rows()[0], vec!["1", "Bob", ""]);
+        assert_eq!(table.rows()[1], vec!["2", "Ann", ""]);
+        assert_eq!(table.rows()[2], vec!["", "", "a@b.c"]);
+    }
+
+    #[test]
+    fn test_parse_mysql_error_names_line() {
+        let input =
+            "+----+------+\n| id | name |\n+----+------+\n| 1  | Bob  |\n| 2  |\n+----+------+";
+
+        let err = MySqlParser.parse(input).unwrap_err();
+
+        assert!(matches!(err, Error::ParseErrorAt { line: 5, .. }));
+        assert_eq!(
+            err.to_string(),
+            "Line 5: Inconsistent column count at row 2: expected 2, found 1"
+        );
+    }
+
+    #[test]
+    fn test_parse_mysql_with_tab_padding() {
+        let input =
+            "+----+-------+\n|\tid | name\t|\n+----+-------+\n|  1 | Al\tice |\n+----+-------+";
+
+        let parser = MySqlParser;
+        let table = parser.parse(input).unwrap();
+
+        assert_eq!(table.headers(), &["id", "name"]);
+        assert_eq!(table.rows()[0], vec!["1", "Al ice"]);
+    }
 }
@@ -0,0 +1,360 @@
+use crate::error::{Error, Result};
+use crate::{Parser, Table};
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Default number of leading records whose keys make up the header
+pub const NDJSON_SCHEMA_LINES: usize = 1000;
+
+/// Parser for newline-delimited JSON (one flat object per line).
+///
+/// The header is the union of keys seen in the first
+/// [`NDJSON_SCHEMA_LINES`] records, in first-seen order. Only that prefix is
+/// held back while the header is settled; later records are converted to rows
+/// as they're read, and a key first appearing after the prefix is an error
+/// rather than a silent extra column. Keys missing from a record give empty
+/// cells.
+///
+/// Strings are unescaped, numbers and booleans keep their literal text, and
+/// `null` becomes an empty cell. Nested objects and arrays are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::parser::NdjsonParser;
+/// use table_extractor::Parser;
+///
+/// let input = "{\"id\":1,\"name\":\"Alice\"}\n{\"id\":2,\"admin\":true}";
+/// let table = NdjsonParser::new().parse(input).unwrap();
+///
+/// assert_eq!(table.headers(), &["id", "name", "admin"]);
+/// assert_eq!(table.rows()[1], vec!["2", "", "true"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NdjsonParser {
+    schema_lines: usize,
+}
+
+impl NdjsonParser {
+    /// Creates a parser inferring the header from the first
+    /// [`NDJSON_SCHEMA_LINES`] records
+    pub fn new() -> Self {
+        Self {
+            schema_lines: NDJSON_SCHEMA_LINES,
+        }
+    }
+
+    /// Sets how many leading records contribute keys to the header
+    pub fn schema_lines(mut self, schema_lines: usize) -> Self {
+        self.schema_lines = schema_lines.max(1);
+        self
+    }
+}
+
+impl Default for NdjsonParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parser for NdjsonParser {
+    fn parse(&self, input: &str) -> Result<Table> {
+        let mut records = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                parse_object(line)
+                    .map(|fields| (idx + 1, fields))
+                    .map_err(|msg| Error::ParseError(format!("NDJSON line {}: {}", idx + 1, msg)))
+            });
+
+        // First pass: buffer the bounded prefix and collect its keys
+        let mut headers = Vec::new();
+        let mut columns: HashMap<String, usize> = HashMap::new();
+        let mut prefix = Vec::new();
+        for record in records.by_ref().take(self.schema_lines) {
+            let (line, fields) = record?;
+            for (key, _) in &fields {
+                if !columns.contains_key(key) {
+                    columns.insert(key.clone(), headers.len());
+                    headers.push(key.clone());
+                }
+            }
+            prefix.push((line, fields));
+        }
+
+        // Second pass: convert the prefix, then stream the remaining records
+        let mut rows = Vec::new();
+        for record in prefix.into_iter().map(Ok).chain(records) {
+            let (line, fields) = record?;
+            let mut row = vec![String::new(); headers.len()];
+            for (key, value) in fields {
+                let Some(&col) = columns.get(&key) else {
+                    return Err(Error::ParseError(format!(
+                        "NDJSON line {}: key '{}' was not in the first {} records",
+                        line, key, self.schema_lines
+                    )));
+                };
+                row[col] = value;
+            }
+            rows.push(row);
+        }
+
+        Table::new_validated(headers, rows)
+    }
+}
+
+/// Parses one line holding a flat JSON object into key/value pairs
+fn parse_object(line: &str) -> std::result::Result<Vec<(String, String)>, String> {
+    let mut chars = line.chars().peekable();
+
+    skip_whitespace(&mut chars);
+    let fields = read_object(&mut chars)?;
+
+    skip_whitespace(&mut chars);
+    match chars.next() {
+        None => Ok(fields),
+        Some(c) => Err(format!("unexpected '{}' after object", c)),
+    }
+}
+
+/// Reads a flat JSON object, from its opening `{` to its closing `}`
+pub(crate) fn read_object(
+    chars: &mut Peekable<Chars>,
+) -> std::result::Result<Vec<(String, String)>, String> {
+    let mut fields = Vec::new();
+
+    expect(chars, '{')?;
+    skip_whitespace(chars);
+
+    if chars.peek() == Some(&'}') {
+        chars.next();
+        return Ok(fields);
+    }
+
+    loop {
+        skip_whitespace(chars);
+        expect(chars, '"')?;
+        let key = parse_string(chars)?;
+        skip_whitespace(chars);
+        expect(chars, ':')?;
+        skip_whitespace(chars);
+        let value = parse_value(chars)?;
+        fields.push((key, value));
+
+        skip_whitespace(chars);
+        match chars.next() {
+            Some(',') => continue,
+            Some('}') => return Ok(fields),
+            Some(c) => return Err(format!("expected ',' or '}}', found '{}'", c)),
+            None => return Err("unterminated object".to_string()),
+        }
+    }
+}
+
+/// Reads a scalar value as cell text: strings are unescaped, numbers and
+/// booleans keep their literal text, and `null` is empty
+pub(crate) fn parse_value(chars: &mut Peekable<Chars>) -> std::result::Result<String, String> {
+    match chars.peek() {
+        Some('"') => {
+            chars.next();
+            parse_string(chars)
+        }
+        Some('{') | Some('[') => Err("nested objects and arrays are not supported".to_string()),
+        Some(_) => {
+            let mut literal = String::new();
+            while let Some(&c) = chars.peek() {
+                if matches!(c, ',' | '}' | ']') || c.is_whitespace() {
+                    break;
+                }
+                literal.push(c);
+                chars.next();
+            }
+            match literal.as_str() {
+                "null" => Ok(String::new()),
+                "true" | "false" => Ok(literal),
+                _ if is_json_number(&literal) => Ok(literal),
+                _ => Err(format!("invalid value '{}'", literal)),
+            }
+        }
+        None => Err("missing value".to_string()),
+    }
+}
+
+/// Reports whether `literal` follows the JSON number grammar, which unlike
+/// Rust's float parsing rejects `NaN`, `inf`, `+1`, `.5` and `01`
+fn is_json_number(literal: &str) -> bool {
+    let digits = |bytes: &[u8]| bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+
+    let bytes = literal.as_bytes();
+    let bytes = bytes.strip_prefix(b"-").unwrap_or(bytes);
+
+    // A lone zero, or digits without a leading zero
+    let int = digits(bytes);
+    if int == 0 || (int > 1 && bytes[0] == b'0') {
+        return false;
+    }
+    let mut rest = &bytes[int..];
+
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let len = digits(fraction);
+        if len == 0 {
+            return false;
+        }
+        rest = &fraction[len..];
+    }
+
+    if let Some(exponent) = rest.strip_prefix(b"e").or_else(|| rest.strip_prefix(b"E")) {
+        let exponent = exponent
+            .strip_prefix(b"+")
+            .or_else(|| exponent.strip_prefix(b"-"))
+            .unwrap_or(exponent);
+        let len = digits(exponent);
+        if len == 0 {
+            return false;
+        }
+        rest = &exponent[len..];
+    }
+
+    rest.is_empty()
+}
+
+/// Reads a string body up to its closing quote (the opening quote is consumed)
+fn parse_string(chars: &mut Peekable<Chars>) -> std::result::Result<String, String> {
+    let mut value = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(value),
+            Some('\\') => match chars.next() {
+                Some('"') => value.push('"'),
+                Some('\\') => value.push('\\'),
+                Some('/') => value.push('/'),
+                Some('b') => value.push('\u{8}'),
+                Some('f') => value.push('\u{c}'),
+                Some('n') => value.push('\n'),
+                Some('r') => value.push('\r'),
+                Some('t') => value.push('\t'),
+                Some('u') => value.push(parse_unicode_escape(chars)?),
+                Some(c) => return Err(format!("invalid escape '\\{}'", c)),
+                None => return Err("unterminated string".to_string()),
+            },
+            Some(c) => value.push(c),
+            None => return Err("unterminated string".to_string()),
+        }
+    }
+}
+
+/// Decodes the hex digits of a `\u` escape, combining surrogate pairs
+fn parse_unicode_escape(chars: &mut Peekable<Chars>) -> std::result::Result<char, String> {
+    let high = read_hex4(chars)?;
+    let code = if (0xD800..0xDC00).contains(&high) {
+        if chars.next() != Some('\\') || chars.next() != Some('u') {
+            return Err("unpaired surrogate in \\u escape".to_string());
+        }
+        let low = read_hex4(chars)?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err("unpaired surrogate in \\u escape".to_string());
+        }
+        0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+    } else {
+        high
+    };
+    char::from_u32(code).ok_or_else(|| "invalid \\u escape".to_string())
+}
+
+fn read_hex4(chars: &mut Peekable<Chars>) -> std::result::Result<u32, String> {
+    let digits: String = chars.take(4).collect();
+    if digits.len() != 4 {
+        return Err("truncated \\u escape".to_string());
+    }
+    u32::from_str_radix(&digits, 16).map_err(|_| format!("invalid \\u escape '{}'", digits))
+}
+
+pub(crate) fn expect(
+    chars: &mut Peekable<Chars>,
+    expected: char,
+) -> std::result::Result<(), String> {
+    match chars.next() {
+        Some(c) if c == expected => Ok(()),
+        Some(c) => Err(format!("expected '{}', found '{}'", expected, c)),
+        None => Err(format!("expected '{}', found end of line", expected)),
+    }
+}
+
+pub(crate) fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ndjson_union_header() {
+        let input = r#"{"id": 1, "name": "Alice"}
+{"id": 2, "email": "bob@example.com"}
+{"name": "Carol \"C\"", "active": false, "id": null}"#;
+
+        let table = NdjsonParser::new().parse(input).unwrap();
+
+        assert_eq!(table.headers(), &["id", "name", "email", "active"]);
+        assert_eq!(table.rows().len(), 3);
+        assert_eq!(table.rows()[0], vec!["1", "Alice", "", ""]);
+        assert_eq!(table.rows()[1], vec!["2", "", "bob@example.com", ""]);
+        assert_eq!(table.rows()[2], vec!["", "Carol \"C\"", "", "false"]);
+    }
+
+    #[test]
+    fn test_parse_ndjson_unseen_key_after_prefix() {
+        let input = "{\"a\":1}\n{\"a\":2}\n{\"b\":3}";
+
+        let err = NdjsonParser::new()
+            .schema_lines(2)
+            .parse(input)
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("NDJSON line 3"), "{}", err);
+        assert!(err.contains("key 'b'"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_ndjson_escapes() {
+        let table = NdjsonParser::new()
+            .parse(r#"{"s":"tab\there é 😀"}"#)
+            .unwrap();
+
+        assert_eq!(table.rows()[0][0], "tab\there \u{e9} \u{1F600}");
+    }
+
+    #[test]
+    fn test_parse_ndjson_rejects_nested_values() {
+        let err = NdjsonParser::new()
+            .parse("{\"a\":1}\n{\"a\":[1,2]}")
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("NDJSON line 2"), "{}", err);
+        assert!(err.contains("nested"), "{}", err);
+    }
+
+    #[test]
+    fn test_json_number_grammar() {
+        for valid in ["0", "-0", "42", "-3.25", "1e10", "2.5E-3", "1e+2"] {
+            assert!(is_json_number(valid), "{}", valid);
+        }
+        for invalid in [
+            "NaN", "inf", "infinity", "-inf", "+1", ".5", "1.", "01", "1e", "0x1",
+        ] {
+            assert!(!is_json_number(invalid), "{}", invalid);
+        }
+
+        let err = NdjsonParser::new()
+            .parse("{\"a\":NaN}")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("invalid value 'NaN'"), "{}", err);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::error::Result;
+use crate::parser::split_pipe_row;
+use crate::{table_from_numbered_rows, Parser, SkippedRow, Table};
+
+/// Parser for Emacs org-mode tables.
+///
+/// Rows look like `| a | b |`, and horizontal rules like `|---+---|` use `+`
+/// where columns meet. The first row is the header; every rule is skipped,
+/// so tables with several rules between row groups read as one table. Lines
+/// not starting with `|`, such as `#+TBLFM:` formulas, are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::parser::OrgParser;
+/// use table_extractor::Parser;
+///
+/// let input = "| id | name  |\n|----+-------|\n|  1 | Alice |";
+/// let table = OrgParser.parse(input).unwrap();
+///
+/// assert_eq!(table.headers(), &["id", "name"]);
+/// assert_eq!(table.rows()[0], vec!["1", "Alice"]);
+/// ```
+pub struct OrgParser;
+
+impl Parser for OrgParser {
+    fn parse(&self, input: &str) -> Result<Table> {
+        parse_rows(input, false).map(|(table, _)| table)
+    }
+
+    fn parse_skipping_bad_rows(&self, input: &str) -> Result<(Table, Vec<SkippedRow>)> {
+        parse_rows(input, true)
+    }
+}
+
+fn parse_rows(input: &str, skip_bad_rows: bool) -> Result<(Table, Vec<SkippedRow>)> {
+    let mut headers = Vec::new();
+    let mut rows = Vec::new();
+
+    for (idx, line) in input.lines().enumerate() {
+        let trimmed = line.trim();
+
+        if !trimmed.starts_with('|') || is_rule_line(trimmed) {
+            continue;
+        }
+
+        let cells = split_pipe_row(trimmed);
+        if headers.is_empty() {
+            headers = cells;
+        } else {
+            rows.push((idx + 1, cells));
+        }
+    }
+
+    table_from_numbered_rows(headers, rows, skip_bad_rows)
+}
+
+/// Reports whether a trimmed line is a horizontal rule such as `|---+---|`
+/// (org also accepts the unfinished `|-` shorthand)
+fn is_rule_line(line: &str) -> bool {
+    line.strip_prefix('|')
+        .map(|rest| rest.strip_suffix('|').unwrap_or(rest))
+        .is_some_and(|rule| rule.starts_with('-') && rule.chars().all(|c| matches!(c, '-' | '+')))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_org() {
+        let input = "| id | name  |
+|----+-------|
+|  1 | Alice |
+|----+-------|
+|  2 | Bob   |
+#+TBLFM: $1=@#";
+
+        let table = OrgParser.parse(input).unwrap();
+
+        assert_eq!(table.headers(), &["id", "name"]);
+        assert_eq!(table.rows().len(), 2);
+        assert_eq!(table.rows()[0], vec!["1", "Alice"]);
+        assert_eq!(table.rows()[1], vec!["2", "Bob"]);
+    }
+
+    #[test]
+    fn test_rule_lines() {
+        assert!(is_rule_line("|---+---|"));
+        assert!(is_rule_line("|-----|"));
+        assert!(is_rule_line("|-"));
+        assert!(!is_rule_line("| - | + |"));
+        assert!(!is_rule_line("|+|"));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::error::Result;
-use crate::{Parser, Table};
+use crate::parser::normalize_cell;
+use crate::{table_from_numbered_rows, Parser, SkippedRow, Table};
 use regex::Regex;
 use std::sync::OnceLock;
 
@@ -7,54 +8,99 @@ use std::sync::OnceLock;
 /// Valid format: `----+-------+-----` (sequences of dashes separated by plus signs)
 static POSTGRES_SEP_LINE: OnceLock<Regex> = OnceLock::new();
 
+/// Regex pattern for the footer lines psql prints after a result:
+/// `(3 rows)`, `(1 row)` and, with `\timing` on, `Time: 3.210 ms`
+static POSTGRES_FOOTER_LINE: OnceLock<Regex> = OnceLock::new();
+
 fn get_postgres_sep_line() -> &'static Regex {
     POSTGRES_SEP_LINE.get_or_init(|| {
         Regex::new(r"^\s*-+(\+-+)+\s*$").expect("Invalid PostgreSQL separator regex")
     })
 }
 
+fn get_postgres_footer_line() -> &'static Regex {
+    POSTGRES_FOOTER_LINE.get_or_init(|| {
+        Regex::new(r"^(\(\d+ rows?\)|Time: \d+(\.\d+)? ms.*)$")
+            .expect("Invalid PostgreSQL footer regex")
+    })
+}
+
+/// Parser for `psql` output.
+///
+/// The first line is the header and data rows follow the `----+----`
+/// separator. Trailing `(N rows)` and `Time:` footer lines, and the blank
+/// lines around them, are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::parser::PostgresParser;
+/// use table_extractor::Parser;
+///
+/// let input = " id | name\n----+-------\n  1 | Alice\n(1 row)\n";
+/// let table = PostgresParser.parse(input).unwrap();
+///
+/// assert_eq!(table.headers(), &["id", "name"]);
+/// assert_eq!(table.rows(), &[vec!["1", "Alice"]]);
+/// ```
 pub struct PostgresParser;
 
 impl Parser for PostgresParser {
     fn parse(&self, input: &str) -> Result<Table> {
-        let lines: Vec<&str> = input.lines().collect();
+        parse_rows(input, false).map(|(table, _)| table)
+    }
+
+    fn parse_skipping_bad_rows(&self, input: &str) -> Result<(Table, Vec<SkippedRow>)> {
+        parse_rows(input, true)
+    }
+}
+
+fn parse_rows(input: &str, skip_bad_rows: bool) -> Result<(Table, Vec<SkippedRow>)> {
+    let mut lines: Vec<&str> = input.lines().collect();
+
+    // Drop the footer from the end; the lines before it are the table
+    while lines
+        .last()
+        .is_some_and(|line| line.trim().is_empty() || is_footer_line(line.trim()))
+    {
+        lines.pop();
+    }
 
-        if lines.is_empty() {
-            return Ok(Table::new(vec![], vec![]));
+    if lines.is_empty() {
+        return Ok((Table::new(vec![], vec![]), Vec::new()));
+    }
+
+    let mut headers = Vec::new();
+    let mut rows = Vec::new();
+    let mut found_separator = false;
+
+    for (idx, line) in lines.into_iter().enumerate() {
+        let trimmed = line.trim();
+
+        // Skip empty lines
+        if trimmed.is_empty() {
+            continue;
         }
 
-        let mut headers = Vec::new();
-        let mut rows = Vec::new();
-        let mut found_separator = false;
-
-        for line in lines {
-            let trimmed = line.trim();
-
-            // Skip empty lines
-            if trimmed.is_empty() {
-                continue;
-            }
-
-            // Check if this is a separator line (contains dashes and pipes)
-            if is_separator_line(trimmed) {
-                found_separator = true;
-                continue;
-            }
-
-            // Parse the row
-            let cells = parse_postgres_row(trimmed);
-
-            if !found_separator && headers.is_empty() {
-                // First row is the header
-                headers = cells;
-            } else if found_separator {
-                // Data rows come after the separator
-                rows.push(cells);
-            }
+        // Check if this is a separator line (contains dashes and pipes)
+        if is_separator_line(trimmed) {
+            found_separator = true;
+            continue;
         }
 
-        Table::new_validated(headers, rows)
+        // Parse the row
+        let cells = parse_postgres_row(trimmed);
+
+        if !found_separator && headers.is_empty() {
+            // First row is the header
+            headers = cells;
+        } else if found_separator {
+            // Data rows come after the separator
+            rows.push((idx + 1, cells));
+        }
     }
+
+    table_from_numbered_rows(headers, rows, skip_bad_rows)
 }
 
 fn is_separator_line(line: &str) -> bool {
@@ -63,6 +109,10 @@ fn is_separator_line(line: &str) -> bool {
     get_postgres_sep_line().is_match(line)
 }
 
+fn is_footer_line(line: &str) -> bool {
+    get_postgres_footer_line().is_match(line)
+}
+
 fn parse_postgres_row(line: &str) -> Vec<String> {
     // Estimate column count for pre-allocation
     let estimated_cols = line.chars().filter(|&c| c == '|').count() + 1;
@@ -72,7 +122,7 @@ fn parse_postgres_row(line: &str) -> Vec<String> {
     // Note: We preserve empty cells as they represent NULL values in PostgreSQL
     // Pre-allocation reduces allocations for large tables
     for cell in line.split('|') {
-        cells.push(cell.trim().to_string());
+        cells.push(normalize_cell(cell));
     }
 
     cells
@@ -132,6 +182,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_postgres_with_tab_padding() {
+        let input = " id\t| name\n----+-------------\n\t1 |\tAlice\tSmith\t\n  2 | Bob\t";
+
+        let parser = PostgresParser;
+        let table = parser.parse(input).unwrap();
+
+        assert_eq!(table.headers(), &["id", "name"]);
+        assert_eq!(table.rows()[0], vec!["1", "Alice Smith"]);
+        assert_eq!(table.rows()[1], vec!["2", "Bob"]);
+    }
+
+    #[test]
+    fn test_parse_postgres_skips_footer() {
+        let input = r#" id | name  | email
+----+-------+---------------
+  1 | Alice | alice@example
+  2 | Bob   |
+  3 | Carol | carol@example
+(3 rows)
+
+Time: 1.482 ms
+"#;
+
+        let table = PostgresParser.parse(input).unwrap();
+
+        assert_eq!(table.headers(), &["id", "name", "email"]);
+        assert_eq!(table.rows().len(), 3);
+        assert_eq!(table.rows()[2], vec!["3", "Carol", "carol@example"]);
+
+        let table = PostgresParser
+            .parse(" a | b\n---+---\n 1 | 2\n(1 row)")
+            .unwrap();
+        assert_eq!(table.rows(), &[vec!["1", "2"]]);
+    }
+
+    #[test]
+    fn test_footer_validation() {
+        assert!(is_footer_line("(0 rows)"));
+        assert!(is_footer_line("(1 row)"));
+        assert!(is_footer_line("(42 rows)"));
+        assert!(is_footer_line("Time: 1234.567 ms (00:01.235)"));
+        assert!(!is_footer_line("(rows)"));
+        assert!(!is_footer_line("  1 | (2 rows)"));
+    }
+
     #[test]
     fn test_separator_validation_valid() {
         // Valid PostgreSQL separator patterns
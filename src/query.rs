@@ -0,0 +1,387 @@
+use crate::error::{Error, Result};
+use crate::types::parse_numeric;
+use crate::Table;
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// A boolean row filter such as `status==active && (age>30 || vip==yes)`.
+///
+/// Comparisons take the form `column OP value`, where `OP` is one of `==`,
+/// `!=`, `>`, `<`, `>=`, `<=` or `~=` (the cell contains the value). They
+/// combine with `&&` and `||`, `&&` binding tighter, and group with
+/// parentheses. Column names and values are bare words, or quoted with `"`
+/// or `'` when they contain spaces or operator characters.
+///
+/// A comparison is numeric when both the cell and the value parse as
+/// numbers, so `age>30` holds for `100`; otherwise strings are compared.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::query::RowQuery;
+/// use table_extractor::Table;
+///
+/// let table = Table::new(
+///     vec!["name".to_string(), "age".to_string()],
+///     vec![
+///         vec!["Alice".to_string(), "9".to_string()],
+///         vec!["Bob".to_string(), "42".to_string()],
+///     ],
+/// );
+///
+/// let query = RowQuery::parse("age>30 || name=='Alice'").unwrap();
+/// assert_eq!(table.select_rows(&query).unwrap().rows().len(), 2);
+///
+/// let query = RowQuery::parse("age>30 && name~=li").unwrap();
+/// assert!(table.select_rows(&query).unwrap().is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowQuery {
+    root: Expr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Compare {
+        column: String,
+        op: Op,
+        value: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Gt,
+    Lt,
+    Ge,
+    Le,
+    Contains,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Op(Op),
+    And,
+    Or,
+    LParen,
+    RParen,
+}
+
+impl RowQuery {
+    /// Parses a query expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ParseError`] describing the first syntax error.
+    pub fn parse(input: &str) -> Result<RowQuery> {
+        let tokens = tokenize(input).map_err(syntax_error)?;
+        let mut tokens = tokens.into_iter().peekable();
+        let root = parse_or(&mut tokens).map_err(syntax_error)?;
+        match tokens.next() {
+            None => Ok(RowQuery { root }),
+            Some(Token::RParen) => Err(syntax_error("unmatched ')'".to_string())),
+            Some(token) => Err(syntax_error(format!("unexpected {}", describe(&token)))),
+        }
+    }
+
+    /// Returns the column names the query compares, in order of appearance
+    fn columns(&self) -> Vec<&str> {
+        let mut columns = Vec::new();
+        let mut stack = vec![&self.root];
+        while let Some(expr) = stack.pop() {
+            match expr {
+                Expr::And(left, right) | Expr::Or(left, right) => {
+                    stack.push(right);
+                    stack.push(left);
+                }
+                Expr::Compare { column, .. } => columns.push(column.as_str()),
+            }
+        }
+        columns
+    }
+}
+
+impl Table {
+    /// Returns a new table with only the rows matching `query`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ColumnNotFound`] if the query names a column the
+    /// table doesn't have.
+    pub fn select_rows(&self, query: &RowQuery) -> Result<Table> {
+        let mut indices = HashMap::new();
+        for column in query.columns() {
+            indices.insert(column, self.find_column(column)?);
+        }
+        Ok(self.filter_rows(|row| evaluate(&query.root, row, &indices)))
+    }
+}
+
+fn evaluate(expr: &Expr, row: &[String], indices: &HashMap<&str, usize>) -> bool {
+    match expr {
+        Expr::And(left, right) => evaluate(left, row, indices) && evaluate(right, row, indices),
+        Expr::Or(left, right) => evaluate(left, row, indices) || evaluate(right, row, indices),
+        Expr::Compare { column, op, value } => compare(&row[indices[column.as_str()]], *op, value),
+    }
+}
+
+fn compare(cell: &str, op: Op, value: &str) -> bool {
+    if op == Op::Contains {
+        return cell.contains(value);
+    }
+
+    let ordering = match (parse_numeric(cell), parse_numeric(value)) {
+        (Some(a), Some(b)) => a.total_cmp(&b),
+        _ => cell.cmp(value),
+    };
+    match op {
+        Op::Eq => ordering.is_eq(),
+        Op::Ne => ordering.is_ne(),
+        Op::Gt => ordering.is_gt(),
+        Op::Lt => ordering.is_lt(),
+        Op::Ge => ordering.is_ge(),
+        Op::Le => ordering.is_le(),
+        Op::Contains => unreachable!("handled above"),
+    }
+}
+
+fn syntax_error(message: String) -> Error {
+    Error::ParseError(format!("Invalid row selection: {}", message))
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Word(word) => format!("'{}'", word),
+        Token::Op(_) => "comparison operator".to_string(),
+        Token::And => "'&&'".to_string(),
+        Token::Or => "'||'".to_string(),
+        Token::LParen => "'('".to_string(),
+        Token::RParen => "')'".to_string(),
+    }
+}
+
+type Tokens = Peekable<std::vec::IntoIter<Token>>;
+
+fn parse_or(tokens: &mut Tokens) -> std::result::Result<Expr, String> {
+    let mut expr = parse_and(tokens)?;
+    while tokens.next_if_eq(&Token::Or).is_some() {
+        expr = Expr::Or(Box::new(expr), Box::new(parse_and(tokens)?));
+    }
+    Ok(expr)
+}
+
+fn parse_and(tokens: &mut Tokens) -> std::result::Result<Expr, String> {
+    let mut expr = parse_primary(tokens)?;
+    while tokens.next_if_eq(&Token::And).is_some() {
+        expr = Expr::And(Box::new(expr), Box::new(parse_primary(tokens)?));
+    }
+    Ok(expr)
+}
+
+fn parse_primary(tokens: &mut Tokens) -> std::result::Result<Expr, String> {
+    match tokens.next() {
+        Some(Token::LParen) => {
+            let expr = parse_or(tokens)?;
+            match tokens.next() {
+                Some(Token::RParen) => Ok(expr),
+                Some(token) => Err(format!("expected ')', found {}", describe(&token))),
+                None => Err("unmatched '('".to_string()),
+            }
+        }
+        Some(Token::Word(column)) => {
+            let op = match tokens.next() {
+                Some(Token::Op(op)) => op,
+                Some(token) => {
+                    return Err(format!(
+                        "expected a comparison operator after '{}', found {}",
+                        column,
+                        describe(&token)
+                    ))
+                }
+                None => return Err(format!("expected a comparison operator after '{}'", column)),
+            };
+            match tokens.next() {
+                Some(Token::Word(value)) => Ok(Expr::Compare { column, op, value }),
+                Some(token) => Err(format!(
+                    "expected a value after '{}', found {}",
+                    column,
+                    describe(&token)
+                )),
+                None => Err(format!("expected a value after '{}'", column)),
+            }
+        }
+        Some(token) => Err(format!("expected a comparison, found {}", describe(&token))),
+        None => Err("expected a comparison, found end of expression".to_string()),
+    }
+}
+
+fn tokenize(input: &str) -> std::result::Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let token = match c {
+            '(' => {
+                chars.next();
+                Token::LParen
+            }
+            ')' => {
+                chars.next();
+                Token::RParen
+            }
+            '&' | '|' => {
+                chars.next();
+                if chars.next() != Some(c) {
+                    return Err(format!("expected '{}{}'", c, c));
+                }
+                if c == '&' {
+                    Token::And
+                } else {
+                    Token::Or
+                }
+            }
+            '=' | '!' | '<' | '>' | '~' => Token::Op(read_op(&mut chars)?),
+            '"' | '\'' => {
+                chars.next();
+                Token::Word(read_quoted(&mut chars, c)?)
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(c) = chars.next_if(|&c| !is_special(c)) {
+                    word.push(c);
+                }
+                Token::Word(word)
+            }
+        };
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+fn is_special(c: char) -> bool {
+    c.is_whitespace() || "()&|=!<>~\"'".contains(c)
+}
+
+fn read_op(chars: &mut Peekable<Chars>) -> std::result::Result<Op, String> {
+    let first = chars.next().expect("caller peeked an operator character");
+    let equals = chars.next_if_eq(&'=').is_some();
+    match (first, equals) {
+        ('=', true) => Ok(Op::Eq),
+        ('!', true) => Ok(Op::Ne),
+        ('<', true) => Ok(Op::Le),
+        ('>', true) => Ok(Op::Ge),
+        ('~', true) => Ok(Op::Contains),
+        ('<', false) => Ok(Op::Lt),
+        ('>', false) => Ok(Op::Gt),
+        _ => Err(format!("unknown operator '{}'", first)),
+    }
+}
+
+fn read_quoted(chars: &mut Peekable<Chars>, quote: char) -> std::result::Result<String, String> {
+    let mut word = String::new();
+    for c in chars.by_ref() {
+        if c == quote {
+            return Ok(word);
+        }
+        word.push(c);
+    }
+    Err(format!("unterminated {} quote", quote))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn people() -> Table {
+        let rows = [
+            ("Alice", "active", "34"),
+            ("Bob", "inactive", "41"),
+            ("Carol", "active", "28"),
+            ("Dan Smith", "active", "100"),
+        ];
+        Table::new(
+            vec!["name".to_string(), "status".to_string(), "age".to_string()],
+            rows.iter()
+                .map(|(name, status, age)| {
+                    vec![name.to_string(), status.to_string(), age.to_string()]
+                })
+                .collect(),
+        )
+    }
+
+    fn names(table: &Table, query: &str) -> Vec<String> {
+        let query = RowQuery::parse(query).unwrap();
+        let selected = table.select_rows(&query).unwrap();
+        selected.rows().iter().map(|row| row[0].clone()).collect()
+    }
+
+    #[test]
+    fn test_and_with_numeric_comparison() {
+        assert_eq!(
+            names(&people(), "status==active && age>30"),
+            vec!["Alice", "Dan Smith"]
+        );
+    }
+
+    #[test]
+    fn test_or_and_precedence() {
+        assert_eq!(
+            names(&people(), "name==Bob || status==active && age<30"),
+            vec!["Bob", "Carol"]
+        );
+        assert_eq!(
+            names(&people(), "(name==Bob || status==active) && age<=34"),
+            vec!["Alice", "Carol"]
+        );
+    }
+
+    #[test]
+    fn test_quoted_values_and_contains() {
+        assert_eq!(names(&people(), "name == 'Dan Smith'"), vec!["Dan Smith"]);
+        assert_eq!(names(&people(), "name~=o && status!=active"), vec!["Bob"]);
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        for (query, message) in [
+            ("status==", "expected a value after 'status'"),
+            (
+                "status active",
+                "expected a comparison operator after 'status', found 'active'",
+            ),
+            ("(age>1", "unmatched '('"),
+            ("age>1)", "unmatched ')'"),
+            ("age>1 & age<5", "expected '&&'"),
+            ("name=Bob", "unknown operator '='"),
+        ] {
+            let err = RowQuery::parse(query).unwrap_err().to_string();
+            assert_eq!(
+                err,
+                format!("Invalid row selection: {}", message),
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_column() {
+        let query = RowQuery::parse("email~=@").unwrap();
+        assert!(matches!(
+            people().select_rows(&query),
+            Err(Error::ColumnNotFound(name)) if name == "email"
+        ));
+    }
+}
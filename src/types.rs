@@ -0,0 +1,623 @@
+use crate::error::{Error, Result};
+use crate::Table;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Logical data type of a table column.
+///
+/// Tables store every cell as a string; a `ColumnType` describes how those
+/// strings should be interpreted when normalizing values.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::types::ColumnType;
+///
+/// assert_eq!(ColumnType::Integer.to_string(), "int");
+/// assert_eq!(ColumnType::Boolean.to_string(), "bool");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    /// Whole numbers such as `42` or `-7`
+    Integer,
+
+    /// Decimal numbers such as `3.14` or `1e3`
+    Float,
+
+    /// Boolean values such as `true`, `no` or `1`
+    Boolean,
+
+    /// Calendar dates, normalized to ISO 8601 (`YYYY-MM-DD`)
+    Date,
+
+    /// Free-form text
+    String,
+}
+
+impl fmt::Display for ColumnType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ColumnType::Integer => "int",
+            ColumnType::Float => "float",
+            ColumnType::Boolean => "bool",
+            ColumnType::Date => "date",
+            ColumnType::String => "str",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl ColumnType {
+    /// Converts a single value to the canonical form for this type.
+    ///
+    /// Returns `None` if the value cannot be interpreted as this type.
+    /// Surrounding whitespace is always ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::types::ColumnType;
+    ///
+    /// assert_eq!(ColumnType::Integer.coerce(" 007 "), Some("7".to_string()));
+    /// assert_eq!(ColumnType::Boolean.coerce("Yes"), Some("true".to_string()));
+    /// assert_eq!(ColumnType::Date.coerce("2024/3/5"), Some("2024-03-05".to_string()));
+    /// assert_eq!(ColumnType::Integer.coerce("abc"), None);
+    /// ```
+    pub fn coerce(&self, value: &str) -> Option<String> {
+        let value = value.trim();
+        match self {
+            ColumnType::Integer => coerce_integer(value),
+            ColumnType::Float => coerce_float(value),
+            ColumnType::Boolean => coerce_boolean(value).map(|b| b.to_string()),
+            ColumnType::Date => coerce_date(value),
+            ColumnType::String => Some(value.to_string()),
+        }
+    }
+}
+
+impl Table {
+    /// Normalizes every value in the named column to the canonical form for `ty`.
+    ///
+    /// Numbers lose leading zeros and `+` signs, booleans become `true`/`false`,
+    /// and dates become ISO 8601 (`YYYY-MM-DD`). Empty cells are left alone.
+    /// Cells that can't be coerced are left unchanged and counted.
+    ///
+    /// Returns the number of non-empty cells that could not be coerced.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::Error::ColumnNotFound`] if no header matches `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    /// use table_extractor::types::ColumnType;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["qty".to_string()],
+    ///     vec![vec![" 007 ".to_string()], vec!["n/a".to_string()]],
+    /// );
+    ///
+    /// let failures = table.coerce_column("qty", ColumnType::Integer).unwrap();
+    /// assert_eq!(failures, 1);
+    /// assert_eq!(table.rows()[0], vec!["7"]);
+    /// assert_eq!(table.rows()[1], vec!["n/a"]);
+    /// ```
+    pub fn coerce_column(&mut self, name: &str, ty: ColumnType) -> Result<usize> {
+        let col = self.find_column(name)?;
+        let mut failures = 0;
+
+        for row in &mut self.rows {
+            let cell = &mut row[col];
+            if cell.trim().is_empty() {
+                continue;
+            }
+            match ty.coerce(cell) {
+                Some(value) => *cell = value,
+                None => failures += 1,
+            }
+        }
+
+        Ok(failures)
+    }
+
+    /// Infers the most specific [`ColumnType`] for each column.
+    ///
+    /// Empty cells are ignored. A column is typed `Integer`, `Float`,
+    /// `Boolean` or `Date` only if every non-empty cell fits that type (checked
+    /// in that order); otherwise, or if the column is entirely empty, it's `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    /// use table_extractor::types::ColumnType;
+    ///
+    /// let table = Table::new(
+    ///     vec!["id".to_string(), "price".to_string(), "name".to_string()],
+    ///     vec![
+    ///         vec!["1".to_string(), "9.99".to_string(), "Tea".to_string()],
+    ///         vec!["2".to_string(), "12".to_string(), "Coffee".to_string()],
+    ///     ],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     table.infer_types(),
+    ///     vec![ColumnType::Integer, ColumnType::Float, ColumnType::String]
+    /// );
+    /// ```
+    pub fn infer_types(&self) -> Vec<ColumnType> {
+        (0..self.headers.len())
+            .map(|col| {
+                let values: Vec<&str> = self
+                    .rows
+                    .iter()
+                    .map(|row| row[col].trim())
+                    .filter(|cell| !cell.is_empty())
+                    .collect();
+
+                if values.is_empty() {
+                    return ColumnType::String;
+                }
+
+                [
+                    ColumnType::Integer,
+                    ColumnType::Float,
+                    ColumnType::Boolean,
+                    ColumnType::Date,
+                ]
+                .into_iter()
+                .find(|ty| values.iter().all(|v| ty.coerce(v).is_some()))
+                .unwrap_or(ColumnType::String)
+            })
+            .collect()
+    }
+
+    /// Reports, per column, whether every non-empty cell is a finite number.
+    ///
+    /// Columns with no non-empty cells are reported as non-numeric. This is
+    /// the shared check behind numeric sorting and column statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["price".to_string(), "name".to_string()],
+    ///     vec![vec!["9.99".to_string(), "Tea".to_string()]],
+    /// );
+    ///
+    /// assert_eq!(table.numeric_columns(), vec![true, false]);
+    /// ```
+    pub fn numeric_columns(&self) -> Vec<bool> {
+        (0..self.headers.len())
+            .map(|col| {
+                let mut values = self
+                    .rows
+                    .iter()
+                    .map(|row| row[col].as_str())
+                    .filter(|cell| !cell.trim().is_empty())
+                    .peekable();
+                values.peek().is_some() && values.all(|v| parse_numeric(v).is_some())
+            })
+            .collect()
+    }
+}
+
+/// How [`Table::sort_by_column`] compares the cells of the sort column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumericMode {
+    /// Compare as numbers when every non-empty cell is numeric
+    #[default]
+    Auto,
+
+    /// Always compare as numbers; cells that aren't numbers sort last
+    Numeric,
+
+    /// Always compare as strings, so `007` stays before `010` and `9`
+    Lexical,
+}
+
+/// Direction of [`Table::sort_by_column_index`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    /// Smallest first
+    #[default]
+    Ascending,
+
+    /// Largest first
+    Descending,
+}
+
+impl Table {
+    /// Sorts the data rows in ascending order of the named column.
+    ///
+    /// `mode` decides between numeric and string comparison; with
+    /// [`NumericMode::Auto`] the column is compared numerically when
+    /// [`Table::numeric_columns`] reports it as numeric. In numeric
+    /// comparisons, cells that don't parse as numbers come after all numbers,
+    /// in string order. The sort is stable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::Error::ColumnNotFound`] if no header matches
+    /// `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::types::NumericMode;
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["zip".to_string()],
+    ///     vec![vec!["10".to_string()], vec!["9".to_string()]],
+    /// );
+    ///
+    /// table.sort_by_column("zip", NumericMode::Auto).unwrap();
+    /// assert_eq!(table.rows()[0], vec!["9"]);
+    ///
+    /// table.sort_by_column("zip", NumericMode::Lexical).unwrap();
+    /// assert_eq!(table.rows()[0], vec!["10"]);
+    /// ```
+    pub fn sort_by_column(&mut self, name: &str, mode: NumericMode) -> Result<()> {
+        let col = self.find_column(name)?;
+        self.sort_rows(col, mode, SortOrder::Ascending);
+        Ok(())
+    }
+
+    /// Sorts the data rows by the column at a 0-based position.
+    ///
+    /// The column is compared numerically when every non-empty cell is a
+    /// number, and as strings otherwise (the [`NumericMode::Auto`] rule of
+    /// [`Table::sort_by_column`]). The sort is stable in both directions, so
+    /// rows with equal keys keep their input order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::Error::InvalidFormat`] if `column` is out of
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use table_extractor::types::SortOrder;
+    /// use table_extractor::Table;
+    ///
+    /// let mut table = Table::new(
+    ///     vec!["name".to_string(), "score".to_string()],
+    ///     vec![
+    ///         vec!["Alice".to_string(), "9".to_string()],
+    ///         vec!["Bob".to_string(), "10".to_string()],
+    ///     ],
+    /// );
+    ///
+    /// table.sort_by_column_index(1, SortOrder::Descending).unwrap();
+    /// assert_eq!(table.rows()[0], vec!["Bob", "10"]);
+    /// assert!(table.sort_by_column_index(2, SortOrder::Ascending).is_err());
+    /// ```
+    pub fn sort_by_column_index(&mut self, column: usize, order: SortOrder) -> Result<()> {
+        if column >= self.headers().len() {
+            return Err(Error::InvalidFormat(format!(
+                "Column index {} is out of range: found {} columns",
+                column,
+                self.headers().len()
+            )));
+        }
+        self.sort_rows(column, NumericMode::Auto, order);
+        Ok(())
+    }
+
+    fn sort_rows(&mut self, col: usize, mode: NumericMode, order: SortOrder) {
+        let numeric = match mode {
+            NumericMode::Auto => self.numeric_columns()[col],
+            NumericMode::Numeric => true,
+            NumericMode::Lexical => false,
+        };
+
+        let compare = move |a: &[String], b: &[String]| {
+            if !numeric {
+                return a[col].cmp(&b[col]);
+            }
+            match (parse_numeric(&a[col]), parse_numeric(&b[col])) {
+                (Some(x), Some(y)) => x.total_cmp(&y),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => a[col].cmp(&b[col]),
+            }
+        };
+
+        // Reversing the comparison rather than the rows keeps ties stable
+        match order {
+            SortOrder::Ascending => self.sort_by(compare),
+            SortOrder::Descending => self.sort_by(|a, b| compare(b, a)),
+        }
+    }
+}
+
+/// Parses a value as a finite number, ignoring surrounding whitespace.
+pub(crate) fn parse_numeric(value: &str) -> Option<f64> {
+    let value = value.trim();
+    // Reject words like "inf" or "NaN" that f64 parsing would otherwise accept
+    if !value
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'e' | 'E'))
+    {
+        return None;
+    }
+    value.parse::<f64>().ok().filter(|n| n.is_finite())
+}
+
+fn coerce_integer(value: &str) -> Option<String> {
+    let (negative, digits) = match value.as_bytes().first()? {
+        b'+' => (false, &value[1..]),
+        b'-' => (true, &value[1..]),
+        _ => (false, value),
+    };
+
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Some("0".to_string());
+    }
+
+    Some(if negative {
+        format!("-{}", digits)
+    } else {
+        digits.to_string()
+    })
+}
+
+fn coerce_float(value: &str) -> Option<String> {
+    parse_numeric(value).map(|n| n.to_string())
+}
+
+pub(crate) fn coerce_boolean(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "true" | "t" | "yes" | "y" | "1" | "on" => Some(true),
+        "false" | "f" | "no" | "n" | "0" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+fn coerce_date(value: &str) -> Option<String> {
+    // Accept year-first dates separated by '-', '/' or '.'
+    let separator = value.chars().find(|c| matches!(c, '-' | '/' | '.'))?;
+    let mut parts = value.split(separator);
+    let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() || year.len() != 4 || month.len() > 2 || day.len() > 2 {
+        return None;
+    }
+
+    let all_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !all_digits(year) || !all_digits(month) || !all_digits(day) {
+        return None;
+    }
+
+    let year: u32 = year.parse().ok()?;
+    let month: u32 = month.parse().ok()?;
+    let day: u32 = day.parse().ok()?;
+
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    Some(format!("{:04}-{:02}-{:02}", year, month, day))
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(table: &Table) -> Vec<&str> {
+        table.rows().iter().map(|row| row[0].as_str()).collect()
+    }
+
+    #[test]
+    fn test_sort_by_column_numeric_mode_override() {
+        let rows = ["010", "007", "9"]
+            .iter()
+            .map(|id| vec![id.to_string()])
+            .collect();
+        let mut table = Table::new(vec!["id".to_string()], rows);
+
+        table.sort_by_column("id", NumericMode::Lexical).unwrap();
+        assert_eq!(ids(&table), vec!["007", "010", "9"]);
+
+        table.sort_by_column("id", NumericMode::Numeric).unwrap();
+        assert_eq!(ids(&table), vec!["007", "9", "010"]);
+
+        table.sort_by_column("id", NumericMode::Auto).unwrap();
+        assert_eq!(ids(&table), vec!["007", "9", "010"]);
+    }
+
+    #[test]
+    fn test_sort_by_column_numeric_puts_non_numbers_last() {
+        let rows = ["b", "2", "", "10", "a"]
+            .iter()
+            .map(|id| vec![id.to_string()])
+            .collect();
+        let mut table = Table::new(vec!["id".to_string()], rows);
+
+        table.sort_by_column("id", NumericMode::Numeric).unwrap();
+        assert_eq!(ids(&table), vec!["2", "10", "", "a", "b"]);
+        assert!(table.sort_by_column("missing", NumericMode::Auto).is_err());
+    }
+
+    #[test]
+    fn test_sort_by_column_index_mixed_column_is_lexical() {
+        let rows = [("1", "10"), ("2", "9"), ("3", "n/a"), ("4", "10")]
+            .iter()
+            .map(|(id, value)| vec![id.to_string(), value.to_string()])
+            .collect();
+        let mut table = Table::new(vec!["id".to_string(), "value".to_string()], rows);
+
+        table.sort_by_column_index(1, SortOrder::Ascending).unwrap();
+        assert_eq!(ids(&table), vec!["1", "4", "2", "3"]);
+    }
+
+    #[test]
+    fn test_sort_by_column_index_descending_numeric() {
+        let rows = [("1", "2.5"), ("2", "10"), ("3", "2.5"), ("4", "-1")]
+            .iter()
+            .map(|(id, value)| vec![id.to_string(), value.to_string()])
+            .collect();
+        let mut table = Table::new(vec!["id".to_string(), "value".to_string()], rows);
+
+        table
+            .sort_by_column_index(1, SortOrder::Descending)
+            .unwrap();
+        // Ties keep their input order
+        assert_eq!(ids(&table), vec!["2", "1", "3", "4"]);
+
+        let err = table
+            .sort_by_column_index(2, SortOrder::Descending)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Column index 2 is out of range: found 2 columns"
+        );
+    }
+
+    #[test]
+    fn test_coerce_messy_integer_column() {
+        let mut table = Table::new(
+            vec!["id".to_string(), "qty".to_string()],
+            vec![
+                vec!["1".to_string(), " 007 ".to_string()],
+                vec!["2".to_string(), "+3".to_string()],
+                vec!["3".to_string(), "-0012".to_string()],
+                vec!["4".to_string(), "000".to_string()],
+            ],
+        );
+
+        let failures = table.coerce_column("qty", ColumnType::Integer).unwrap();
+
+        assert_eq!(failures, 0);
+        let qty: Vec<&str> = table.rows().iter().map(|r| r[1].as_str()).collect();
+        assert_eq!(qty, vec!["7", "3", "-12", "0"]);
+    }
+
+    #[test]
+    fn test_coerce_counts_failures_and_skips_empty() {
+        let mut table = Table::new(
+            vec!["qty".to_string()],
+            vec![
+                vec!["12".to_string()],
+                vec!["".to_string()],
+                vec!["twelve".to_string()],
+                vec!["1.5".to_string()],
+            ],
+        );
+
+        let failures = table.coerce_column("qty", ColumnType::Integer).unwrap();
+
+        assert_eq!(failures, 2);
+        assert_eq!(table.rows()[1], vec![""]);
+        assert_eq!(table.rows()[2], vec!["twelve"]);
+        assert_eq!(table.rows()[3], vec!["1.5"]);
+    }
+
+    #[test]
+    fn test_coerce_missing_column() {
+        let mut table = Table::new(vec!["id".to_string()], vec![]);
+        let result = table.coerce_column("qty", ColumnType::Integer);
+        assert!(matches!(
+            result,
+            Err(crate::error::Error::ColumnNotFound(name)) if name == "qty"
+        ));
+    }
+
+    #[test]
+    fn test_infer_types() {
+        let table = Table::new(
+            vec![
+                "id".to_string(),
+                "score".to_string(),
+                "active".to_string(),
+                "joined".to_string(),
+                "empty".to_string(),
+            ],
+            vec![
+                vec![
+                    "1".to_string(),
+                    "2.5".to_string(),
+                    "yes".to_string(),
+                    "2024-01-31".to_string(),
+                    "".to_string(),
+                ],
+                vec![
+                    "".to_string(),
+                    "3".to_string(),
+                    "false".to_string(),
+                    "2024/2/1".to_string(),
+                    "".to_string(),
+                ],
+            ],
+        );
+
+        assert_eq!(
+            table.infer_types(),
+            vec![
+                ColumnType::Integer,
+                ColumnType::Float,
+                ColumnType::Boolean,
+                ColumnType::Date,
+                ColumnType::String,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_numeric_columns() {
+        let table = Table::new(
+            vec![
+                "amount".to_string(),
+                "label".to_string(),
+                "blank".to_string(),
+            ],
+            vec![
+                vec!["1.5".to_string(), "a".to_string(), "".to_string()],
+                vec!["".to_string(), "2".to_string(), " ".to_string()],
+                vec!["-3e2".to_string(), "c".to_string(), "".to_string()],
+            ],
+        );
+
+        assert_eq!(table.numeric_columns(), vec![true, false, false]);
+    }
+
+    #[test]
+    fn test_coerce_other_types() {
+        assert_eq!(ColumnType::Float.coerce("+3.50"), Some("3.5".to_string()));
+        assert_eq!(ColumnType::Float.coerce("inf"), None);
+        assert_eq!(
+            ColumnType::Boolean.coerce(" OFF "),
+            Some("false".to_string())
+        );
+        assert_eq!(
+            ColumnType::Date.coerce("2024.12.31"),
+            Some("2024-12-31".to_string())
+        );
+        assert_eq!(ColumnType::Date.coerce("2023-02-29"), None);
+        assert_eq!(
+            ColumnType::String.coerce("  a b  "),
+            Some("a b".to_string())
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use crate::error::{Error, Result};
+use crate::{Table, Writer};
+use std::io::Write as IoWrite;
+
+/// Writes a table in the bordered style of the MySQL command-line client.
+///
+/// Each column is padded to the display width of its widest value, with wide
+/// characters such as CJK ideographs and emoji counting as two columns, and
+/// framed by `+---+` border lines. Values are left-aligned and written as-is,
+/// so a cell containing `|` won't parse back as the same table.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::writer::BoxWriter;
+/// use table_extractor::{Table, Writer};
+///
+/// let table = Table::new(
+///     vec!["id".to_string(), "name".to_string()],
+///     vec![vec!["1".to_string(), "Alice".to_string()]],
+/// );
+///
+/// let mut output = Vec::new();
+/// BoxWriter.write(&table, &mut output).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "+----+-------+\n| id | name  |\n+----+-------+\n| 1  | Alice |\n+----+-------+\n"
+/// );
+/// ```
+pub struct BoxWriter;
+
+impl Writer for BoxWriter {
+    fn write(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
+        // A bordered row can't span lines, so there's no faithful rendering
+        let has_line_break = |cells: &[String]| cells.iter().any(|cell| cell.contains('\n'));
+        if has_line_break(table.headers()) {
+            return Err(Error::InvalidFormat(
+                "Header contains a line break, which bordered tables can't represent".to_string(),
+            ));
+        }
+        if let Some(idx) = table.rows().iter().position(|row| has_line_break(row)) {
+            return Err(Error::InvalidFormat(format!(
+                "Row {} contains a line break, which bordered tables can't represent",
+                idx + 1
+            )));
+        }
+
+        let mut widths: Vec<usize> = table.headers().iter().map(|h| display_width(h)).collect();
+        for row in table.rows() {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(display_width(cell));
+            }
+        }
+
+        let border: String = widths
+            .iter()
+            .map(|width| format!("+{}", "-".repeat(width + 2)))
+            .collect::<String>()
+            + "+";
+
+        writeln!(output, "{}", border)?;
+        write_row(output, table.headers(), &widths)?;
+        writeln!(output, "{}", border)?;
+        for row in table.rows() {
+            write_row(output, row, &widths)?;
+        }
+        // An empty table still gets a closing border under the header
+        if !table.is_empty() {
+            writeln!(output, "{}", border)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_row(output: &mut dyn IoWrite, cells: &[String], widths: &[usize]) -> Result<()> {
+    write!(output, "|")?;
+    for (cell, width) in cells.iter().zip(widths) {
+        let padding = width - display_width(cell);
+        write!(output, " {}{} |", cell, " ".repeat(padding))?;
+    }
+    writeln!(output)?;
+    Ok(())
+}
+
+/// Approximates the number of terminal columns `text` occupies.
+///
+/// East Asian wide characters and emoji count as two columns, combining
+/// marks and other zero-width characters as none, and everything else as one.
+fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+fn char_width(c: char) -> usize {
+    let code = c as u32;
+    match code {
+        // Combining marks, zero-width spaces and joiners, variation selectors
+        0x0300..=0x036F | 0x200B..=0x200F | 0x20D0..=0x20FF | 0xFE00..=0xFE0F => 0,
+        // Hangul Jamo, CJK, Hangul syllables, compatibility and fullwidth forms
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x20000..=0x3FFFD => 2,
+        // Symbols that terminals draw as emoji
+        0x231A..=0x231B
+        | 0x23E9..=0x23EC
+        | 0x23F0
+        | 0x23F3
+        | 0x25FD..=0x25FE
+        | 0x2614..=0x2615
+        | 0x2648..=0x2653
+        | 0x267F
+        | 0x2693
+        | 0x26A1
+        | 0x26AA..=0x26AB
+        | 0x26BD..=0x26BE
+        | 0x26C4..=0x26C5
+        | 0x26CE
+        | 0x26D4
+        | 0x26EA
+        | 0x26F2..=0x26F3
+        | 0x26F5
+        | 0x26FA
+        | 0x26FD
+        | 0x2705
+        | 0x270A..=0x270B
+        | 0x2728
+        | 0x274C
+        | 0x274E
+        | 0x2753..=0x2755
+        | 0x2757
+        | 0x2795..=0x2797
+        | 0x27B0
+        | 0x27BF
+        | 0x2B1B..=0x2B1C
+        | 0x2B50
+        | 0x2B55
+        | 0x1F004
+        | 0x1F0CF
+        | 0x1F18E
+        | 0x1F191..=0x1F19A
+        | 0x1F200..=0x1F2FF
+        | 0x1F300..=0x1F64F
+        | 0x1F680..=0x1F6FF
+        | 0x1F7E0..=0x1F7EB
+        | 0x1F90C..=0x1F9FF
+        | 0x1FA70..=0x1FAFF => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::MySqlParser;
+    use crate::Parser;
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!(display_width("Alice"), 5);
+        assert_eq!(display_width("コーヒー ☕"), 11);
+        assert_eq!(display_width("绿茶 🍵"), 7);
+        assert_eq!(display_width("Крепкий чай"), 11);
+        assert_eq!(display_width("e\u{301}"), 1);
+    }
+
+    #[test]
+    fn test_unicode_round_trip() {
+        let input = std::fs::read_to_string("tests/fixtures/mysql_unicode.txt").unwrap();
+        let table = MySqlParser.parse(&input).unwrap();
+
+        let mut output = Vec::new();
+        BoxWriter.write(&table, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        let widths: Vec<usize> = output.lines().map(display_width).collect();
+        assert!(widths.iter().all(|&w| w == widths[0]), "{}", output);
+        assert_eq!(MySqlParser.parse(&output).unwrap(), table);
+    }
+
+    #[test]
+    fn test_empty_table_has_header_box() {
+        let table = Table::new(vec!["id".to_string()], vec![]);
+
+        let mut output = Vec::new();
+        BoxWriter.write(&table, &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "+----+\n| id |\n+----+\n"
+        );
+    }
+}
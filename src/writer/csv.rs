@@ -1,13 +1,43 @@
 use crate::error::Result;
 use crate::{Table, Writer};
-use csv::WriterBuilder;
+use csv::{Terminator, WriterBuilder};
 use std::io::Write as IoWrite;
 
-pub struct CsvWriter;
+/// Writes a table as RFC 4180 CSV, quoting fields only where needed.
+///
+/// Build one with [`CsvWriter::new`] or [`CsvWriter::default`]; it has
+/// options, so unlike the other plain writers it isn't a unit struct.
+pub struct CsvWriter {
+    delimiter: u8,
+    comment_header: Option<char>,
+    crlf: bool,
+}
 
 impl CsvWriter {
     pub fn new() -> Self {
-        Self
+        Self::with_delimiter(b',')
+    }
+
+    /// Writes CSV-style quoted fields separated by `delimiter` instead of a comma.
+    pub fn with_delimiter(delimiter: u8) -> Self {
+        Self {
+            delimiter,
+            comment_header: None,
+            crlf: false,
+        }
+    }
+
+    /// Prefixes the header line with `marker` (e.g. `#`), so tools that skip
+    /// comment lines only see the data rows
+    pub fn comment_header(mut self, marker: char) -> Self {
+        self.comment_header = Some(marker);
+        self
+    }
+
+    /// Ends lines with `\r\n` instead of `\n`, as Windows tools expect
+    pub fn crlf(mut self) -> Self {
+        self.crlf = true;
+        self
     }
 }
 
@@ -19,9 +49,22 @@ impl Default for CsvWriter {
 
 impl Writer for CsvWriter {
     fn write(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
+        // The marker goes outside the record so it is never quoted with the first header
+        if let Some(marker) = self.comment_header {
+            write!(output, "{}", marker)?;
+        }
+
         // Write directly to output instead of buffering in Vec
         // The csv crate uses an internal buffer, and stdout is already wrapped in BufWriter
-        let mut writer = WriterBuilder::new().has_headers(false).from_writer(output);
+        let mut writer = WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .has_headers(false)
+            .terminator(if self.crlf {
+                Terminator::CRLF
+            } else {
+                Terminator::Any(b'\n')
+            })
+            .from_writer(output);
 
         // Write headers
         writer.write_record(table.headers())?;
@@ -60,6 +103,36 @@ mod tests {
         assert_eq!(result, "id,name\n1,Alice\n2,Bob\n");
     }
 
+    #[test]
+    fn test_write_csv_crlf() {
+        let table = Table::new(
+            vec!["id".to_string(), "note".to_string()],
+            vec![vec!["1".to_string(), "two\nlines".to_string()]],
+        );
+
+        let mut output = Vec::new();
+        CsvWriter::new().crlf().write(&table, &mut output).unwrap();
+
+        // Line breaks inside quoted fields are data and stay as they are
+        let result = String::from_utf8(output).unwrap();
+        assert_eq!(result, "id,note\r\n1,\"two\nlines\"\r\n");
+    }
+
+    #[test]
+    fn test_write_csv_comment_header() {
+        let table = Table::new(
+            vec!["first, last".to_string(), "id".to_string()],
+            vec![vec!["Alice".to_string(), "1".to_string()]],
+        );
+
+        let writer = CsvWriter::new().comment_header('#');
+        let mut output = Vec::new();
+        writer.write(&table, &mut output).unwrap();
+
+        let result = String::from_utf8(output).unwrap();
+        assert_eq!(result, "#\"first, last\",id\nAlice,1\n");
+    }
+
     #[test]
     fn test_write_csv_with_quotes() {
         let table = Table::new(
@@ -77,4 +150,21 @@ mod tests {
         let result = String::from_utf8(output).unwrap();
         assert_eq!(result, "id,name\n1,\"Alice, Bob\"\n");
     }
+
+    #[test]
+    fn test_write_with_delimiter_quotes_conflicts() {
+        let table = Table::new(
+            vec!["id".to_string(), "note".to_string()],
+            vec![vec!["1".to_string(), "a\tb".to_string()]],
+        );
+
+        let writer = CsvWriter::with_delimiter(b'\t');
+        let mut output = Vec::new();
+        writer.write(&table, &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "id\tnote\n1\t\"a\tb\"\n"
+        );
+    }
 }
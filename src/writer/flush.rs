@@ -0,0 +1,100 @@
+use std::io::{self, Write as IoWrite};
+
+/// Wraps an output stream and flushes it after every `n` lines.
+///
+/// Writers buffer output for throughput, which delays data reaching slow
+/// consumers such as pagers. Wrapping the output in `FlushEvery` trades some
+/// of that throughput for interactivity. Lines are counted by newline bytes,
+/// so a CSV cell containing a newline counts as an extra line.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::writer::{FlushEvery, TsvWriter};
+/// use table_extractor::{Table, Writer};
+///
+/// let table = Table::new(
+///     vec!["id".to_string()],
+///     vec![vec!["1".to_string()], vec!["2".to_string()]],
+/// );
+///
+/// let mut output = FlushEvery::new(Vec::new(), 1);
+/// TsvWriter::default().write(&table, &mut output).unwrap();
+/// assert_eq!(output.into_inner(), b"id\n1\n2\n");
+/// ```
+pub struct FlushEvery<W: IoWrite> {
+    inner: W,
+    every: usize,
+    pending_lines: usize,
+}
+
+impl<W: IoWrite> FlushEvery<W> {
+    /// Creates a wrapper that flushes `inner` after every `every` lines.
+    ///
+    /// A value of `0` is treated as `1`.
+    pub fn new(inner: W, every: usize) -> Self {
+        Self {
+            inner,
+            every: every.max(1),
+            pending_lines: 0,
+        }
+    }
+
+    /// Consumes the wrapper and returns the underlying stream.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: IoWrite> IoWrite for FlushEvery<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.pending_lines += buf[..written].iter().filter(|&&b| b == b'\n').count();
+        if self.pending_lines >= self.every {
+            self.inner.flush()?;
+            self.pending_lines = 0;
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.pending_lines = 0;
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records how many times it was flushed
+    #[derive(Default)]
+    struct CountingSink {
+        data: Vec<u8>,
+        flushes: usize,
+    }
+
+    impl IoWrite for CountingSink {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.data.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.flushes += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_flushes_every_n_lines() {
+        let mut output = FlushEvery::new(CountingSink::default(), 2);
+        for i in 0..5 {
+            writeln!(output, "row{}", i).unwrap();
+        }
+
+        let sink = output.into_inner();
+        assert_eq!(sink.flushes, 2);
+        assert_eq!(sink.data, b"row0\nrow1\nrow2\nrow3\nrow4\n");
+    }
+}
@@ -0,0 +1,93 @@
+use crate::error::Result;
+use crate::{Table, Writer};
+use std::io::Write as IoWrite;
+
+/// Writes a table as an HTML `<table>` with a `<thead>` and `<tbody>`.
+///
+/// Headers and cells are HTML-escaped, so the output is safe to embed in a
+/// page as-is.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::writer::HtmlWriter;
+/// use table_extractor::{Table, Writer};
+///
+/// let table = Table::new(
+///     vec!["id".to_string()],
+///     vec![vec!["<1>".to_string()]],
+/// );
+///
+/// let mut output = Vec::new();
+/// HtmlWriter.write(&table, &mut output).unwrap();
+///
+/// let html = String::from_utf8(output).unwrap();
+/// assert!(html.contains("<th>id</th>"));
+/// assert!(html.contains("<td>&lt;1&gt;</td>"));
+/// ```
+pub struct HtmlWriter;
+
+impl Writer for HtmlWriter {
+    fn write(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
+        writeln!(output, "<table>")?;
+
+        writeln!(output, "  <thead>")?;
+        write_row(output, "th", table.headers())?;
+        writeln!(output, "  </thead>")?;
+
+        writeln!(output, "  <tbody>")?;
+        for row in table.rows() {
+            write_row(output, "td", row)?;
+        }
+        writeln!(output, "  </tbody>")?;
+
+        writeln!(output, "</table>")?;
+        Ok(())
+    }
+}
+
+fn write_row(output: &mut dyn IoWrite, tag: &str, cells: &[String]) -> Result<()> {
+    write!(output, "    <tr>")?;
+    for cell in cells {
+        write!(output, "<{}>{}</{}>", tag, escape(cell), tag)?;
+    }
+    writeln!(output, "</tr>")?;
+    Ok(())
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_html() {
+        let table = Table::new(
+            vec!["id".to_string(), "name".to_string()],
+            vec![vec!["1".to_string(), "Tom & \"Jerry\"".to_string()]],
+        );
+
+        let mut output = Vec::new();
+        HtmlWriter.write(&table, &mut output).unwrap();
+
+        let result = String::from_utf8(output).unwrap();
+        assert_eq!(
+            result,
+            "<table>\n  <thead>\n    <tr><th>id</th><th>name</th></tr>\n  </thead>\n  <tbody>\n    <tr><td>1</td><td>Tom &amp; &quot;Jerry&quot;</td></tr>\n  </tbody>\n</table>\n"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use crate::error::Result;
+use crate::{deduped_headers, Table, Writer};
+use std::io::Write as IoWrite;
+
+/// Writes a table as a JSON array with one object per row.
+///
+/// Object keys follow the header order exactly and every value is emitted as
+/// a string, so no information is lost converting numbers or dates. Repeated
+/// headers get a numeric suffix (`name_2`, `name_3`, ...) so no key is lost.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::writer::JsonWriter;
+/// use table_extractor::{Table, Writer};
+///
+/// let table = Table::new(
+///     vec!["id".to_string(), "name".to_string()],
+///     vec![vec!["1".to_string(), "Alice".to_string()]],
+/// );
+///
+/// let mut output = Vec::new();
+/// JsonWriter::new().write(&table, &mut output).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "[{\"id\":\"1\",\"name\":\"Alice\"}]\n"
+/// );
+/// ```
+pub struct JsonWriter {
+    pretty: bool,
+}
+
+impl JsonWriter {
+    /// Creates a writer producing compact, single-line JSON
+    pub fn new() -> Self {
+        Self { pretty: false }
+    }
+
+    /// Creates a writer producing indented JSON with one key per line
+    pub fn pretty() -> Self {
+        Self { pretty: true }
+    }
+}
+
+impl Default for JsonWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Writer for JsonWriter {
+    fn write(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
+        let keys = object_keys(table);
+
+        let (row_sep, key_sep, indent, key_indent) = if self.pretty {
+            ("\n", ": ", "  ", "    ")
+        } else {
+            ("", ":", "", "")
+        };
+
+        write!(output, "[")?;
+        for (row_idx, row) in table.rows().iter().enumerate() {
+            if row_idx > 0 {
+                write!(output, ",")?;
+            }
+            write!(output, "{}{}{{", row_sep, indent)?;
+            for (col_idx, (key, value)) in keys.iter().zip(row).enumerate() {
+                if col_idx > 0 {
+                    write!(output, ",")?;
+                }
+                write!(
+                    output,
+                    "{}{}{}{}{}",
+                    row_sep,
+                    key_indent,
+                    key,
+                    key_sep,
+                    escape(value)
+                )?;
+            }
+            if !row.is_empty() {
+                write!(output, "{}{}", row_sep, indent)?;
+            }
+            write!(output, "}}")?;
+        }
+        if !table.rows().is_empty() {
+            write!(output, "{}", row_sep)?;
+        }
+        writeln!(output, "]")?;
+
+        Ok(())
+    }
+}
+
+/// Returns the quoted object key for each column, suffixing repeated headers.
+///
+/// Keys are written in header order directly rather than through a map, so
+/// the output is deterministic.
+pub(crate) fn object_keys(table: &Table) -> Vec<String> {
+    deduped_headers(table.headers())
+        .iter()
+        .map(|name| escape(name))
+        .collect()
+}
+
+/// Quotes a string as a JSON string literal
+pub(crate) fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_table() -> Table {
+        Table::new(
+            vec!["name".to_string(), "id".to_string()],
+            vec![
+                vec!["Alice".to_string(), "1".to_string()],
+                vec!["Bob".to_string(), "2".to_string()],
+            ],
+        )
+    }
+
+    #[test]
+    fn test_keys_follow_header_order() {
+        let mut output = Vec::new();
+        JsonWriter::new()
+            .write(&sample_table(), &mut output)
+            .unwrap();
+
+        let result = String::from_utf8(output).unwrap();
+        assert_eq!(
+            result,
+            "[{\"name\":\"Alice\",\"id\":\"1\"},{\"name\":\"Bob\",\"id\":\"2\"}]\n"
+        );
+    }
+
+    #[test]
+    fn test_pretty_output() {
+        let mut output = Vec::new();
+        JsonWriter::pretty()
+            .write(&sample_table(), &mut output)
+            .unwrap();
+
+        let result = String::from_utf8(output).unwrap();
+        assert_eq!(
+            result,
+            "[\n  {\n    \"name\": \"Alice\",\n    \"id\": \"1\"\n  },\n  {\n    \"name\": \"Bob\",\n    \"id\": \"2\"\n  }\n]\n"
+        );
+    }
+
+    #[test]
+    fn test_empty_table() {
+        let table = Table::new(vec!["id".to_string()], vec![]);
+        for writer in [JsonWriter::new(), JsonWriter::pretty()] {
+            let mut output = Vec::new();
+            writer.write(&table, &mut output).unwrap();
+            assert_eq!(String::from_utf8(output).unwrap(), "[]\n");
+        }
+    }
+
+    #[test]
+    fn test_duplicate_headers_get_suffixes() {
+        let table = Table::new(
+            vec!["id".to_string(), "id".to_string(), "id_2".to_string()],
+            vec![vec!["1".to_string(), "2".to_string(), "3".to_string()]],
+        );
+
+        let mut output = Vec::new();
+        JsonWriter::new().write(&table, &mut output).unwrap();
+
+        let result = String::from_utf8(output).unwrap();
+        assert_eq!(result, "[{\"id\":\"1\",\"id_2\":\"2\",\"id_2_2\":\"3\"}]\n");
+    }
+
+    #[test]
+    fn test_escaping() {
+        let table = Table::new(
+            vec!["say \"hi\"".to_string()],
+            vec![vec!["a\\b\nc\u{1}".to_string()]],
+        );
+
+        let mut output = Vec::new();
+        JsonWriter::new().write(&table, &mut output).unwrap();
+
+        let result = String::from_utf8(output).unwrap();
+        assert_eq!(result, "[{\"say \\\"hi\\\"\":\"a\\\\b\\nc\\u0001\"}]\n");
+    }
+}
@@ -0,0 +1,137 @@
+use crate::error::Result;
+use crate::types::ColumnType;
+use crate::writer::json::{escape, object_keys};
+use crate::{Table, Writer};
+use std::io::Write as IoWrite;
+
+/// Writes newline-delimited JSON (one object per row) with typed values.
+///
+/// Column types come from [`Table::infer_types`]: integer and float columns
+/// become JSON numbers, boolean columns `true`/`false`, and everything else
+/// strings. A numeric column with a zero-padded value such as `007` stays
+/// strings, since IDs and zip codes lose their padding as numbers. Empty
+/// cells are `null`. Repeated headers are suffixed as in
+/// [`JsonWriter`](crate::writer::JsonWriter).
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::writer::JsonlTypedWriter;
+/// use table_extractor::{Table, Writer};
+///
+/// let table = Table::new(
+///     vec!["id".to_string(), "active".to_string()],
+///     vec![
+///         vec!["1".to_string(), "true".to_string()],
+///         vec!["2".to_string(), "".to_string()],
+///     ],
+/// );
+///
+/// let mut output = Vec::new();
+/// JsonlTypedWriter.write(&table, &mut output).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "{\"id\":1,\"active\":true}\n{\"id\":2,\"active\":null}\n"
+/// );
+/// ```
+pub struct JsonlTypedWriter;
+
+impl Writer for JsonlTypedWriter {
+    fn write(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
+        let keys = object_keys(table);
+        let mut types = table.infer_types();
+        for (col_idx, ty) in types.iter_mut().enumerate() {
+            let numeric = matches!(ty, ColumnType::Integer | ColumnType::Float);
+            if numeric && table.rows().iter().any(|row| is_zero_padded(&row[col_idx])) {
+                *ty = ColumnType::String;
+            }
+        }
+
+        for row in table.rows() {
+            write!(output, "{{")?;
+            for (col_idx, ((key, ty), value)) in keys.iter().zip(&types).zip(row).enumerate() {
+                if col_idx > 0 {
+                    write!(output, ",")?;
+                }
+                write!(output, "{}:{}", key, typed_value(*ty, value))?;
+            }
+            writeln!(output, "}}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Renders one cell as a JSON literal of its column's type
+fn typed_value(ty: ColumnType, value: &str) -> String {
+    if value.trim().is_empty() {
+        return "null".to_string();
+    }
+    match ty {
+        ColumnType::Integer | ColumnType::Float | ColumnType::Boolean => {
+            // Every non-empty cell of an inferred column coerces, so the
+            // string fallback is only a safeguard
+            ty.coerce(value).unwrap_or_else(|| escape(value))
+        }
+        ColumnType::Date | ColumnType::String => escape(value),
+    }
+}
+
+/// Reports whether a number has a leading zero before another digit, as in
+/// `007` or `-01.5`
+fn is_zero_padded(value: &str) -> bool {
+    let value = value.trim();
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value).as_bytes();
+    digits.len() > 1 && digits[0] == b'0' && digits[1].is_ascii_digit()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_values() {
+        let table = Table::new(
+            vec![
+                "id".to_string(),
+                "price".to_string(),
+                "active".to_string(),
+                "code".to_string(),
+            ],
+            vec![
+                vec![
+                    "007".to_string(),
+                    "1.50".to_string(),
+                    "yes".to_string(),
+                    "A1".to_string(),
+                ],
+                vec![
+                    "8".to_string(),
+                    "2".to_string(),
+                    "no".to_string(),
+                    "2".to_string(),
+                ],
+            ],
+        );
+
+        let mut output = Vec::new();
+        JsonlTypedWriter.write(&table, &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"id\":\"007\",\"price\":1.5,\"active\":true,\"code\":\"A1\"}\n\
+             {\"id\":\"8\",\"price\":2,\"active\":false,\"code\":\"2\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_zero_padded() {
+        assert!(is_zero_padded("007"));
+        assert!(is_zero_padded("-01.5"));
+        assert!(!is_zero_padded("0"));
+        assert!(!is_zero_padded("0.5"));
+        assert!(!is_zero_padded("-0"));
+        assert!(!is_zero_padded("10"));
+    }
+}
@@ -0,0 +1,100 @@
+use crate::error::{Error, Result};
+use crate::{Table, Writer};
+use std::io::Write as IoWrite;
+
+/// Writes a table as a GitHub-flavored Markdown table.
+///
+/// Every cell is padded with one space on each side, and literal `|`
+/// characters are escaped as `\|` so the output parses back cleanly.
+/// Columns are not aligned to a common width.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::writer::MarkdownWriter;
+/// use table_extractor::{Table, Writer};
+///
+/// let table = Table::new(
+///     vec!["id".to_string(), "name".to_string()],
+///     vec![vec!["1".to_string(), "Alice".to_string()]],
+/// );
+///
+/// let mut output = Vec::new();
+/// MarkdownWriter.write(&table, &mut output).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "| id | name |\n|---|---|\n| 1 | Alice |\n"
+/// );
+/// ```
+pub struct MarkdownWriter;
+
+impl Writer for MarkdownWriter {
+    fn write(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
+        // A Markdown table row can't span lines, so there's no faithful rendering
+        for (idx, row) in table.rows().iter().enumerate() {
+            if row.iter().any(|cell| cell.contains('\n')) {
+                return Err(Error::InvalidFormat(format!(
+                    "Row {} contains a line break, which Markdown tables can't represent",
+                    idx + 1
+                )));
+            }
+        }
+        if table.headers().iter().any(|h| h.contains('\n')) {
+            return Err(Error::InvalidFormat(
+                "Header contains a line break, which Markdown tables can't represent".to_string(),
+            ));
+        }
+
+        write_row(output, table.headers())?;
+        writeln!(output, "|{}", "---|".repeat(table.column_count()))?;
+        for row in table.rows() {
+            write_row(output, row)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_row(output: &mut dyn IoWrite, cells: &[String]) -> Result<()> {
+    write!(output, "|")?;
+    for cell in cells {
+        write!(output, " {} |", cell.replace('|', "\\|"))?;
+    }
+    writeln!(output)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::MarkdownParser;
+    use crate::Parser;
+
+    #[test]
+    fn test_write_markdown_escapes_pipes() {
+        let table = Table::new(
+            vec!["expr".to_string(), "note".to_string()],
+            vec![vec!["a|b".to_string(), "".to_string()]],
+        );
+
+        let mut output = Vec::new();
+        MarkdownWriter.write(&table, &mut output).unwrap();
+
+        let result = String::from_utf8(output).unwrap();
+        assert_eq!(result, "| expr | note |\n|---|---|\n| a\\|b |  |\n");
+
+        // Round trip through the parser
+        let parsed = MarkdownParser.parse(&result).unwrap();
+        assert_eq!(parsed.rows(), table.rows());
+    }
+
+    #[test]
+    fn test_reject_line_break() {
+        let table = Table::new(vec!["a".to_string()], vec![vec!["x\ny".to_string()]]);
+
+        let mut output = Vec::new();
+        let err = MarkdownWriter.write(&table, &mut output).unwrap_err();
+        assert!(err.to_string().contains("Row 1"));
+    }
+}
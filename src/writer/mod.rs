@@ -1,5 +1,113 @@
+pub mod box_writer;
 pub mod csv;
+pub mod flush;
+pub mod html;
+pub mod json;
+pub mod jsonl_typed;
+pub mod markdown;
+pub mod ndjson;
+pub mod sql;
+pub mod tree;
 pub mod tsv;
+pub mod typed_tsv;
 
 pub use self::csv::CsvWriter;
+pub use box_writer::BoxWriter;
+pub use flush::FlushEvery;
+pub use html::HtmlWriter;
+pub use json::JsonWriter;
+pub use jsonl_typed::JsonlTypedWriter;
+pub use markdown::MarkdownWriter;
+pub use ndjson::NdJsonWriter;
+pub use sql::SqlWriter;
+pub use tree::TreeWriter;
 pub use tsv::TsvWriter;
+pub use typed_tsv::TypedTsvWriter;
+
+use crate::error::{Error, Result};
+use crate::{Format, Table, Writer};
+use std::io::Write as IoWrite;
+
+/// Writes `table` to `out` in `format` using that format's default writer.
+///
+/// This is the writing counterpart of picking a parser for a [`Format`]:
+/// CSV, TSV and PSV go through [`CsvWriter`] and [`TsvWriter`], Markdown
+/// through [`MarkdownWriter`], MySQL through [`BoxWriter`], JSON through
+/// [`JsonWriter`] and HTML through [`HtmlWriter`]. Writers with options,
+/// such as pretty-printed JSON, are still available directly.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidFormat`] for formats that can only be read, such
+/// as PostgreSQL, org-mode and plain lines, and any error from the writer.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::writer::write_table;
+/// use table_extractor::{Format, Table};
+///
+/// let table = Table::new(vec!["id".to_string()], vec![vec!["1".to_string()]]);
+///
+/// let mut output = Vec::new();
+/// write_table(&table, Format::CSV, &mut output).unwrap();
+/// assert_eq!(String::from_utf8(output).unwrap(), "id\n1\n");
+///
+/// assert!(write_table(&table, Format::Org, &mut Vec::new()).is_err());
+/// ```
+pub fn write_table(table: &Table, format: Format, out: &mut dyn IoWrite) -> Result<()> {
+    match format {
+        Format::CSV => CsvWriter::new().write(table, out),
+        Format::TSV => TsvWriter::default().write(table, out),
+        Format::Psv => TsvWriter::new('|').write(table, out),
+        Format::Markdown => MarkdownWriter.write(table, out),
+        Format::MySQL => BoxWriter.write(table, out),
+        Format::Json => JsonWriter::new().write(table, out),
+        Format::Html => HtmlWriter.write(table, out),
+        Format::PostgreSQL | Format::Org | Format::PlainLines => Err(Error::InvalidFormat(
+            format!("There is no {} writer", format),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{CsvParser, HtmlParser, JsonParser, MarkdownParser, MySqlParser};
+    use crate::Parser;
+
+    #[test]
+    fn test_write_table_round_trips_each_format() {
+        let table = Table::new(
+            vec!["id".to_string(), "name".to_string()],
+            vec![
+                vec!["1".to_string(), "Alice".to_string()],
+                vec!["2".to_string(), "Bob Smith".to_string()],
+            ],
+        );
+
+        for format in Format::all() {
+            let parser: Box<dyn Parser> = match format {
+                Format::CSV => Box::new(CsvParser::csv()),
+                Format::TSV => Box::new(CsvParser::tsv()),
+                Format::Psv => Box::new(CsvParser::new(b'|')),
+                Format::Markdown => Box::new(MarkdownParser),
+                Format::MySQL => Box::new(MySqlParser),
+                Format::Json => Box::new(JsonParser),
+                Format::Html => Box::new(HtmlParser::new()),
+                Format::PostgreSQL | Format::Org | Format::PlainLines => {
+                    assert!(matches!(
+                        write_table(&table, *format, &mut Vec::new()),
+                        Err(Error::InvalidFormat(_))
+                    ));
+                    continue;
+                }
+            };
+
+            let mut output = Vec::new();
+            write_table(&table, *format, &mut output).unwrap();
+            let output = String::from_utf8(output).unwrap();
+            assert_eq!(parser.parse(&output).unwrap(), table, "{}", format);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::error::Result;
+use crate::writer::json::{escape, object_keys};
+use crate::{Table, Writer};
+use std::io::Write as IoWrite;
+
+/// Writes newline-delimited JSON: one compact object per row, keyed by header.
+///
+/// Values are strings, as with [`JsonWriter`](crate::writer::JsonWriter), but
+/// there is no enclosing array, so each line can be consumed on its own by
+/// `jq` or a log pipeline. A table without rows produces no output.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::writer::NdJsonWriter;
+/// use table_extractor::{Table, Writer};
+///
+/// let table = Table::new(
+///     vec!["id".to_string(), "name".to_string()],
+///     vec![
+///         vec!["1".to_string(), "Alice".to_string()],
+///         vec!["2".to_string(), "Bob".to_string()],
+///     ],
+/// );
+///
+/// let mut output = Vec::new();
+/// NdJsonWriter.write(&table, &mut output).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "{\"id\":\"1\",\"name\":\"Alice\"}\n{\"id\":\"2\",\"name\":\"Bob\"}\n"
+/// );
+/// ```
+pub struct NdJsonWriter;
+
+impl Writer for NdJsonWriter {
+    fn write(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
+        let keys = object_keys(table);
+
+        for row in table.rows() {
+            write!(output, "{{")?;
+            for (col_idx, (key, value)) in keys.iter().zip(row).enumerate() {
+                if col_idx > 0 {
+                    write!(output, ",")?;
+                }
+                write!(output, "{}:{}", key, escape(value))?;
+            }
+            writeln!(output, "}}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(table: &Table) -> String {
+        let mut output = Vec::new();
+        NdJsonWriter.write(table, &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_escapes_quotes_tabs_and_unicode() {
+        let table = Table::new(
+            vec!["id".to_string(), "note".to_string()],
+            vec![
+                vec!["1".to_string(), "say \"hi\"\tnow".to_string()],
+                vec!["2".to_string(), "コーヒー ☕ naïve".to_string()],
+                vec!["3".to_string(), "back\\slash\nnew line".to_string()],
+            ],
+        );
+
+        assert_eq!(
+            write(&table),
+            "{\"id\":\"1\",\"note\":\"say \\\"hi\\\"\\tnow\"}\n\
+             {\"id\":\"2\",\"note\":\"コーヒー ☕ naïve\"}\n\
+             {\"id\":\"3\",\"note\":\"back\\\\slash\\nnew line\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_repeated_headers_and_empty_table() {
+        let table = Table::new(
+            vec!["id".to_string(), "id".to_string()],
+            vec![vec!["1".to_string(), "2".to_string()]],
+        );
+        assert_eq!(write(&table), "{\"id\":\"1\",\"id_2\":\"2\"}\n");
+
+        let empty = Table::new(vec!["id".to_string()], vec![]);
+        assert_eq!(write(&empty), "");
+    }
+}
@@ -0,0 +1,220 @@
+use crate::error::Result;
+use crate::{Table, Writer};
+use std::io::Write as IoWrite;
+
+/// Table name used by [`SqlWriter::new`]
+pub const DEFAULT_SQL_TABLE: &str = "data";
+
+/// Writes a table as SQL `INSERT` statements, one per row.
+///
+/// Every value is written as a single-quoted string literal with embedded
+/// quotes doubled. The table and column names are written bare when they
+/// consist only of ASCII letters, digits and `_`, don't start with a digit
+/// and aren't a common reserved word such as `order`; otherwise they are
+/// double-quoted.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::writer::SqlWriter;
+/// use table_extractor::{Table, Writer};
+///
+/// let table = Table::new(
+///     vec!["id".to_string(), "name".to_string()],
+///     vec![vec!["1".to_string(), "O'Brien".to_string()]],
+/// );
+///
+/// let mut output = Vec::new();
+/// SqlWriter::new().table("users").write(&table, &mut output).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "INSERT INTO users (id, name) VALUES ('1', 'O''Brien');\n"
+/// );
+/// ```
+pub struct SqlWriter {
+    table: String,
+}
+
+impl SqlWriter {
+    /// Creates a writer inserting into [`DEFAULT_SQL_TABLE`]
+    pub fn new() -> Self {
+        Self {
+            table: DEFAULT_SQL_TABLE.to_string(),
+        }
+    }
+
+    /// Sets the table the statements insert into
+    pub fn table(mut self, table: &str) -> Self {
+        self.table = table.to_string();
+        self
+    }
+}
+
+impl Default for SqlWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Writer for SqlWriter {
+    fn write(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
+        let prefix = format!(
+            "INSERT INTO {} ({}) VALUES (",
+            identifier(&self.table),
+            table
+                .headers()
+                .iter()
+                .map(|header| identifier(header))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        for row in table.rows() {
+            let values: Vec<String> = row.iter().map(|cell| literal(cell)).collect();
+            writeln!(output, "{}{});", prefix, values.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Keywords reserved by SQLite, PostgreSQL or MySQL that are likely to turn
+/// up as column names
+const RESERVED_WORDS: &[&str] = &[
+    "add",
+    "all",
+    "alter",
+    "and",
+    "as",
+    "asc",
+    "between",
+    "by",
+    "case",
+    "check",
+    "column",
+    "constraint",
+    "create",
+    "cross",
+    "default",
+    "delete",
+    "desc",
+    "distinct",
+    "drop",
+    "else",
+    "end",
+    "exists",
+    "foreign",
+    "from",
+    "full",
+    "group",
+    "having",
+    "in",
+    "index",
+    "inner",
+    "insert",
+    "into",
+    "is",
+    "join",
+    "key",
+    "left",
+    "like",
+    "limit",
+    "natural",
+    "not",
+    "null",
+    "offset",
+    "on",
+    "or",
+    "order",
+    "outer",
+    "primary",
+    "references",
+    "right",
+    "select",
+    "set",
+    "table",
+    "then",
+    "to",
+    "union",
+    "unique",
+    "update",
+    "user",
+    "using",
+    "values",
+    "when",
+    "where",
+    "with",
+];
+
+/// Double-quotes an identifier unless it is a plain `[A-Za-z_][A-Za-z0-9_]*`
+/// name that isn't a reserved word
+fn identifier(name: &str) -> String {
+    let is_plain = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !RESERVED_WORDS.contains(&name.to_ascii_lowercase().as_str());
+    if is_plain {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+/// Quotes a value as a SQL string literal
+fn literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_sql_quotes_identifiers_and_values() {
+        let table = Table::new(
+            vec!["id".to_string(), "full name".to_string()],
+            vec![
+                vec!["1".to_string(), "it's".to_string()],
+                vec!["2".to_string(), "".to_string()],
+            ],
+        );
+
+        let mut output = Vec::new();
+        SqlWriter::new()
+            .table("my \"users\"")
+            .write(&table, &mut output)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "INSERT INTO \"my \"\"users\"\"\" (id, \"full name\") VALUES ('1', 'it''s');\n\
+             INSERT INTO \"my \"\"users\"\"\" (id, \"full name\") VALUES ('2', '');\n"
+        );
+    }
+
+    #[test]
+    fn test_identifier_quotes_leading_digits_and_reserved_words() {
+        assert_eq!(identifier("user_id"), "user_id");
+        assert_eq!(identifier("_rank"), "_rank");
+        assert_eq!(identifier("1col"), "\"1col\"");
+        assert_eq!(identifier("order"), "\"order\"");
+        assert_eq!(identifier("SELECT"), "\"SELECT\"");
+        assert_eq!(identifier(""), "\"\"");
+    }
+
+    #[test]
+    fn test_write_sql_default_table_name() {
+        let table = Table::new(vec!["id".to_string()], vec![vec!["7".to_string()]]);
+
+        let mut output = Vec::new();
+        SqlWriter::default().write(&table, &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "INSERT INTO data (id) VALUES ('7');\n"
+        );
+    }
+}
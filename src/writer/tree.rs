@@ -0,0 +1,90 @@
+use crate::error::Result;
+use crate::{Table, Writer};
+use std::io::Write as IoWrite;
+
+/// Writes each row as a `Row N` node with one `header: value` branch per
+/// column, for reading wide rows by eye.
+///
+/// Rows are numbered from 1 and separated by a blank line. Continuation lines
+/// of multi-line values stay aligned under their branch. The output is not
+/// meant to be parsed back.
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::writer::TreeWriter;
+/// use table_extractor::{Table, Writer};
+///
+/// let table = Table::new(
+///     vec!["id".to_string(), "name".to_string()],
+///     vec![vec!["1".to_string(), "Alice".to_string()]],
+/// );
+///
+/// let mut output = Vec::new();
+/// TreeWriter.write(&table, &mut output).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "Row 1\n├─ id: 1\n└─ name: Alice\n"
+/// );
+/// ```
+pub struct TreeWriter;
+
+impl Writer for TreeWriter {
+    fn write(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
+        let last = table.headers().len().saturating_sub(1);
+
+        for (row_idx, row) in table.rows().iter().enumerate() {
+            if row_idx > 0 {
+                writeln!(output)?;
+            }
+            writeln!(output, "Row {}", row_idx + 1)?;
+
+            for (col_idx, (header, value)) in table.headers().iter().zip(row).enumerate() {
+                let (branch, continuation) = if col_idx == last {
+                    ("└─", "   ")
+                } else {
+                    ("├─", "│  ")
+                };
+
+                let mut lines = value.lines();
+                writeln!(
+                    output,
+                    "{} {}: {}",
+                    branch,
+                    header,
+                    lines.next().unwrap_or("")
+                )?;
+                for line in lines {
+                    writeln!(output, "{}{}", continuation, line)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_tree_multiline_value() {
+        let table = Table::new(
+            vec!["note".to_string(), "id".to_string()],
+            vec![
+                vec!["a\nb".to_string(), "1".to_string()],
+                vec!["".to_string(), "2".to_string()],
+            ],
+        );
+
+        let mut output = Vec::new();
+        TreeWriter.write(&table, &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Row 1\n├─ note: a\n│  b\n└─ id: 1\n\nRow 2\n├─ note: \n└─ id: 2\n"
+        );
+    }
+}
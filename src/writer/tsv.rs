@@ -4,11 +4,22 @@ use std::io::Write as IoWrite;
 
 pub struct TsvWriter {
     delimiter: char,
+    comment_header: Option<char>,
 }
 
 impl TsvWriter {
     pub fn new(delimiter: char) -> Self {
-        Self { delimiter }
+        Self {
+            delimiter,
+            comment_header: None,
+        }
+    }
+
+    /// Prefixes the header line with `marker` (e.g. `#`), so tools that skip
+    /// comment lines only see the data rows
+    pub fn comment_header(mut self, marker: char) -> Self {
+        self.comment_header = Some(marker);
+        self
     }
 }
 
@@ -18,8 +29,9 @@ impl Default for TsvWriter {
     }
 }
 
-impl Writer for TsvWriter {
-    fn write(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
+impl TsvWriter {
+    /// Validates and writes the header line
+    pub(crate) fn write_headers(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
         // Validate headers don't contain delimiter to prevent data corruption
         for header in table.headers() {
             if header.contains(self.delimiter) {
@@ -32,14 +44,21 @@ impl Writer for TsvWriter {
             }
         }
 
-        // Write headers
-        writeln!(
-            output,
-            "{}",
-            table.headers().join(&self.delimiter.to_string())
-        )?;
+        let mut line = String::new();
+        if let Some(marker) = self.comment_header {
+            line.push(marker);
+        }
+        self.push_line(&mut line, table.headers());
+        output.write_all(line.as_bytes())?;
 
-        // Validate and write rows
+        Ok(())
+    }
+
+    /// Validates and writes the data rows
+    pub(crate) fn write_rows(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
+        // One buffer reused for every row keeps it to a single write and no
+        // allocation per row
+        let mut line = String::new();
         for (idx, row) in table.rows().iter().enumerate() {
             for cell in row {
                 if cell.contains(self.delimiter) {
@@ -51,11 +70,31 @@ impl Writer for TsvWriter {
                     ));
                 }
             }
-            writeln!(output, "{}", row.join(&self.delimiter.to_string()))?;
+            line.clear();
+            self.push_line(&mut line, row);
+            output.write_all(line.as_bytes())?;
         }
 
         Ok(())
     }
+
+    /// Appends `cells` joined by the delimiter, plus a newline, to `line`
+    fn push_line(&self, line: &mut String, cells: &[String]) {
+        for (i, cell) in cells.iter().enumerate() {
+            if i > 0 {
+                line.push(self.delimiter);
+            }
+            line.push_str(cell);
+        }
+        line.push('\n');
+    }
+}
+
+impl Writer for TsvWriter {
+    fn write(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
+        self.write_headers(table, output)?;
+        self.write_rows(table, output)
+    }
 }
 
 #[cfg(test)]
@@ -80,6 +119,21 @@ mod tests {
         assert_eq!(result, "id\tname\n1\tAlice\n2\tBob\n");
     }
 
+    #[test]
+    fn test_write_comment_header() {
+        let table = Table::new(
+            vec!["id".to_string(), "name".to_string()],
+            vec![vec!["1".to_string(), "Alice".to_string()]],
+        );
+
+        let writer = TsvWriter::default().comment_header('#');
+        let mut output = Vec::new();
+        writer.write(&table, &mut output).unwrap();
+
+        let result = String::from_utf8(output).unwrap();
+        assert_eq!(result, "#id\tname\n1\tAlice\n");
+    }
+
     #[test]
     fn test_write_custom_delimiter() {
         let table = Table::new(
@@ -95,6 +149,34 @@ mod tests {
         assert_eq!(result, "id|name\n1|Alice\n");
     }
 
+    #[test]
+    fn test_output_matches_joined_lines() {
+        let table = Table::new(
+            vec!["id".to_string(), "name".to_string(), "note".to_string()],
+            vec![
+                vec!["1".to_string(), "Zoë".to_string(), String::new()],
+                vec!["2".to_string(), "日本".to_string(), "a \"b\" c".to_string()],
+                vec![String::new(), String::new(), String::new()],
+            ],
+        );
+
+        for delimiter in ['\t', '|', '¦'] {
+            let mut expected = String::new();
+            for line in
+                std::iter::once(table.headers()).chain(table.rows().iter().map(Vec::as_slice))
+            {
+                expected.push_str(&line.join(&delimiter.to_string()));
+                expected.push('\n');
+            }
+
+            let mut output = Vec::new();
+            TsvWriter::new(delimiter)
+                .write(&table, &mut output)
+                .unwrap();
+            assert_eq!(String::from_utf8(output).unwrap(), expected);
+        }
+    }
+
     #[test]
     fn test_reject_tab_in_data() {
         let table = Table::new(
@@ -0,0 +1,85 @@
+use crate::error::Result;
+use crate::writer::TsvWriter;
+use crate::{Table, Writer};
+use std::io::Write as IoWrite;
+
+/// Writes TSV with a second line declaring each column's inferred type.
+///
+/// The type line (`int`, `float`, `bool`, `date` or `str`) comes immediately
+/// after the header so typed loaders can pick it up; other consumers can
+/// simply skip it. Types are inferred with [`Table::infer_types`].
+///
+/// # Examples
+///
+/// ```
+/// use table_extractor::writer::TypedTsvWriter;
+/// use table_extractor::{Table, Writer};
+///
+/// let table = Table::new(
+///     vec!["id".to_string(), "name".to_string()],
+///     vec![vec!["1".to_string(), "Alice".to_string()]],
+/// );
+///
+/// let mut output = Vec::new();
+/// TypedTsvWriter::default().write(&table, &mut output).unwrap();
+///
+/// assert_eq!(String::from_utf8(output).unwrap(), "id\tname\nint\tstr\n1\tAlice\n");
+/// ```
+#[derive(Default)]
+pub struct TypedTsvWriter {
+    inner: TsvWriter,
+}
+
+impl Writer for TypedTsvWriter {
+    fn write(&self, table: &Table, output: &mut dyn IoWrite) -> Result<()> {
+        self.inner.write_headers(table, output)?;
+
+        let types: Vec<String> = table.infer_types().iter().map(|t| t.to_string()).collect();
+        writeln!(output, "{}", types.join("\t"))?;
+
+        self.inner.write_rows(table, output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_line_follows_header() {
+        let table = Table::new(
+            vec![
+                "id".to_string(),
+                "price".to_string(),
+                "name".to_string(),
+                "active".to_string(),
+            ],
+            vec![
+                vec![
+                    "1".to_string(),
+                    "9.99".to_string(),
+                    "Tea".to_string(),
+                    "yes".to_string(),
+                ],
+                vec![
+                    "2".to_string(),
+                    "12".to_string(),
+                    "Coffee".to_string(),
+                    "no".to_string(),
+                ],
+            ],
+        );
+
+        let mut output = Vec::new();
+        TypedTsvWriter::default()
+            .write(&table, &mut output)
+            .unwrap();
+
+        let result = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "id\tprice\tname\tactive");
+        assert_eq!(lines[1], "int\tfloat\tstr\tbool");
+        assert_eq!(lines[2], "1\t9.99\tTea\tyes");
+        assert_eq!(lines.len(), 4);
+    }
+}
@@ -33,6 +33,33 @@ fn test_completions_fish() {
         .stdout(predicate::str::contains("complete -c tabx"));
 }
 
+#[test]
+fn test_completions_bash_include_format_values() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("completions")
+        .arg("bash")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "auto sniff json mysql postgresql org markdown psv tsv csv lines html",
+        ))
+        .stdout(predicate::str::contains("tsv csv"));
+}
+
+#[test]
+fn test_input_format_alias_and_case() {
+    let input = " id | name\n----+------\n  1 | Alice";
+    for format in ["psql", "PostgreSQL"] {
+        let mut cmd = Command::cargo_bin("tabx").unwrap();
+        cmd.arg("-i")
+            .arg(format)
+            .write_stdin(input)
+            .assert()
+            .success()
+            .stdout("id\tname\n1\tAlice\n");
+    }
+}
+
 #[test]
 fn test_completions_help() {
     let mut cmd = Command::cargo_bin("tabx").unwrap();
@@ -242,7 +269,7 @@ fn test_invalid_input_format() {
         .assert()
         .failure()
         .code(2)
-        .stderr(predicate::str::contains("Invalid format"));
+        .stderr(predicate::str::contains("invalid value 'invalid'"));
 }
 
 #[test]
@@ -254,7 +281,23 @@ fn test_invalid_output_format() {
         .assert()
         .failure()
         .code(2)
-        .stderr(predicate::str::contains("Invalid output format"));
+        .stderr(predicate::str::contains(
+            "Unsupported output format 'invalid'",
+        ));
+}
+
+#[test]
+fn test_output_format_typo_suggests_closest() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "cvs"])
+        .write_stdin("id,name\n1,Alice")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("Did you mean 'csv'?"))
+        .stderr(predicate::str::contains(
+            "Available formats: tsv, csv, psv, typed-tsv, json, ndjson, jsonl-typed, markdown, mysql, sql, tree, debug",
+        ));
 }
 
 #[test]
@@ -639,3 +682,1548 @@ fn test_stdin_still_works() {
         .stdout(predicate::str::contains("id\tname"))
         .stdout(predicate::str::contains("1\tAlice"));
 }
+
+#[test]
+fn test_columns_literal_and_wildcard() {
+    let input = "metric_cpu,name,id,metric_mem\n0.5,web,1,128\n0.9,db,2,512";
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--columns")
+        .arg("id,metric_*")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\tmetric_cpu\tmetric_mem\n1\t0.5\t128\n2\t0.9\t512\n");
+}
+
+#[test]
+fn test_columns_unknown_column() {
+    let input = "id,name\n1,Alice";
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--columns")
+        .arg("email")
+        .write_stdin(input)
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("Column not found: 'email'"));
+}
+
+#[test]
+fn test_transpose() {
+    let input = "id,name\n1,Alice\n2,Bob";
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--transpose")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("column\trow_1\trow_2\nid\t1\t2\nname\tAlice\tBob\n");
+}
+
+#[test]
+fn test_row_numbers() {
+    let input = "id,name\n10,Alice\n20,Bob\n30,Carol";
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--row-numbers")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("#\tid\tname\n1\t10\tAlice\n2\t20\tBob\n3\t30\tCarol\n");
+}
+
+#[test]
+fn test_row_numbers_after_transpose() {
+    // Numbering runs after other transforms, so it numbers the final rows
+    let input = "id,name\n10,Alice";
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--transpose")
+        .arg("--row-numbers")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("#\tcolumn\trow_1\n1\tid\t10\n2\tname\tAlice\n");
+}
+
+#[test]
+fn test_postgres_tab_padding_converts_to_tsv() {
+    let input = " id\t| name\n----+-------------\n\t1 |\tAlice\tSmith\t\n  2 | Bob";
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice Smith\n2\tBob\n")
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_drop_empty_columns() {
+    let input = "id,notes,email\n1,,a@b.c\n2,,";
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--drop-empty-columns")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\temail\n1\ta@b.c\n2\t\n");
+}
+
+#[test]
+fn test_emit_format_comment() {
+    let input = "id\tname\n1\tAlice";
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--emit-format-comment")
+        .arg("-o")
+        .arg("csv")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("# format: csv\nid,name\n1,Alice\n");
+}
+
+#[test]
+fn test_format_comment_round_trip() {
+    // The emitted comment line must be consumed rather than parsed as the header
+    let input = "id,name\n1,\"Alice | Bob\"";
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    let output = cmd
+        .arg("--emit-format-comment")
+        .arg("-o")
+        .arg("csv")
+        .write_stdin(input)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--honor-format-hint")
+        .write_stdin(output.stdout)
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice | Bob\n");
+}
+
+#[test]
+fn test_flush_every_output_unchanged() {
+    let mut input = String::from("id,name\n");
+    for i in 0..100 {
+        input.push_str(&format!("{},name{}\n", i, i));
+    }
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    let expected = cmd.write_stdin(input.clone()).output().unwrap();
+    assert!(expected.status.success());
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--flush-every")
+        .arg("7")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout(expected.stdout);
+}
+
+#[test]
+fn test_flush_every_rejects_zero() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--flush-every")
+        .arg("0")
+        .write_stdin("id\n1")
+        .assert()
+        .failure()
+        .code(2);
+}
+
+#[test]
+fn test_multiple_files_concatenated() {
+    let first = "test_concat_first.csv";
+    let second = "test_concat_second.csv";
+    fs::write(first, "id,name\n1,Alice").unwrap();
+    fs::write(second, "id,name\n2,Bob").unwrap();
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg(first)
+        .arg(second)
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n2\tBob\n");
+
+    fs::remove_file(first).unwrap();
+    fs::remove_file(second).unwrap();
+}
+
+#[test]
+fn test_ignore_header_case_when_concatenating() {
+    let first = "test_header_case_first.csv";
+    let second = "test_header_case_second.csv";
+    fs::write(first, "ID,Name\n1,Alice").unwrap();
+    fs::write(second, "id,name\n2,Bob").unwrap();
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg(first)
+        .arg(second)
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains("Header mismatch"));
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--ignore-header-case")
+        .arg(first)
+        .arg(second)
+        .assert()
+        .success()
+        .stdout("ID\tName\n1\tAlice\n2\tBob\n");
+
+    fs::remove_file(first).unwrap();
+    fs::remove_file(second).unwrap();
+}
+
+#[test]
+fn test_debug_output() {
+    let input = "| id | name |\n|----|------|\n| 1 | Alice |\n| 2 |  |";
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("-o")
+        .arg("debug")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("format: markdown (detected)"))
+        .stdout(predicate::str::contains("headers: [id] [name]"))
+        .stdout(predicate::str::contains("row 1: [1] [Alice]"))
+        .stdout(predicate::str::contains("row 2: [2] []"))
+        .stdout(predicate::str::contains("widths: 2, 5"));
+}
+
+#[test]
+fn test_debug_output_reports_format_hint() {
+    let input = "# format: psv\nid|name\n1|Alice\n";
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--honor-format-hint", "-o", "debug"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("format: psv (from hint)"));
+}
+
+#[test]
+fn test_first_columns() {
+    let input = "a,b,c,d,e,f,g,h,i,j\n1,2,3,4,5,6,7,8,9,10";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--first-columns")
+        .arg("3")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("a\tb\tc\n1\t2\t3\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--first-columns")
+        .arg("20")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("a\tb\tc\td\te\tf\tg\th\ti\tj\n1\t2\t3\t4\t5\t6\t7\t8\t9\t10\n");
+}
+
+#[test]
+fn test_stats_reports_types_and_empty_cells() {
+    let input = "id,name,email\n1,Alice,\n2,,b@c.d\n3,Carol,";
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("stats")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "rows: 3\ncolumns: 3\napprox size: ",
+        ))
+        .stdout(predicate::str::contains("column\ttype\tempty\tempty_pct"))
+        .stdout(predicate::str::contains("id\tint\t0\t0.0%"))
+        .stdout(predicate::str::contains("name\tstr\t1\t33.3%"))
+        .stdout(predicate::str::contains("email\tstr\t2\t66.7%"));
+}
+
+#[test]
+fn test_trim_trailing_empty_columns() {
+    let input = "id,name\n1,Alice,\n2,Bob,";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.write_stdin(input).assert().failure().code(1);
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--trim-trailing-empty-columns")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n2\tBob\n")
+        .stderr(predicate::str::contains("Dropped a trailing empty column"));
+}
+
+#[test]
+fn test_typed_tsv_output() {
+    let input = "id,price,name,active\n1,9.99,Tea,yes\n2,12,Coffee,no";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "typed-tsv"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\tprice\tname\tactive\nint\tfloat\tstr\tbool\n1\t9.99\tTea\tyes\n2\t12\tCoffee\tno\n");
+}
+
+#[test]
+fn test_paste_subcommand() {
+    let left = "test_paste_left.csv";
+    let right = "test_paste_right.csv";
+    fs::write(left, "id,name\n1,Alice\n2,Bob").unwrap();
+    fs::write(right, "name,age\nSmith,30\nJones,25").unwrap();
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "csv", "paste", left, right])
+        .assert()
+        .success()
+        .stdout("id,name,name_2,age\n1,Alice,Smith,30\n2,Bob,Jones,25\n");
+
+    fs::remove_file(left).unwrap();
+    fs::remove_file(right).unwrap();
+}
+
+#[test]
+fn test_paste_row_count_mismatch() {
+    let left = "test_paste_mismatch_left.csv";
+    let right = "test_paste_mismatch_right.csv";
+    fs::write(left, "id\n1\n2").unwrap();
+    fs::write(right, "age\n30").unwrap();
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["paste", left, right])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains("Row count mismatch"));
+
+    fs::remove_file(left).unwrap();
+    fs::remove_file(right).unwrap();
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn test_normalize_unicode() {
+    // Same word, composed then decomposed
+    let input = "name\ncaf\u{e9}\ncafe\u{301}";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--normalize-unicode")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("name\ncaf\u{e9}\ncaf\u{e9}\n");
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn test_normalize_unicode_dedups_equivalent_values() {
+    let input = "id,name\n1,caf\u{e9}\n2,cafe\u{301}";
+
+    // Without normalization the two spellings count as distinct values
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["frequency", "-s", "name"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("field\tvalue\tcount\nname\tcafe\u{301}\t1\nname\tcaf\u{e9}\t1\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--normalize-unicode", "frequency", "-s", "name"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("field\tvalue\tcount\nname\tcaf\u{e9}\t2\n");
+}
+
+#[test]
+fn test_strict_headers() {
+    let input = "id,name,id\n1,Alice,2";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.write_stdin(input).assert().success();
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--strict-headers")
+        .write_stdin(input)
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains("Duplicate header: 'id'"));
+}
+
+#[test]
+fn test_input_format_sniff_skips_structural_detection() {
+    // A data row of dashes and a plus looks like a PostgreSQL separator
+    let input = "rule\na|b\n---+---\nc|d";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.write_stdin(input).assert().failure().code(1);
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "sniff"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("rule\na|b\n---+---\nc|d\n");
+}
+
+#[test]
+fn test_input_format_sniff_picks_delimiter() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "sniff"])
+        .write_stdin("id;name\n1;Alice")
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n");
+}
+
+#[test]
+fn test_broken_pipe_exits_quietly() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::process::Stdio;
+
+    let mut input = String::from("id,name\n");
+    for i in 0..200_000 {
+        input.push_str(&format!("{},name{}\n", i, i));
+    }
+
+    let mut child = std::process::Command::new(assert_cmd::cargo::cargo_bin("tabx"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut stdin = child.stdin.take().unwrap();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+
+    // Read only the first line, then close the pipe like `head -n 1` would
+    let mut first_line = String::new();
+    BufReader::new(child.stdout.take().unwrap())
+        .read_line(&mut first_line)
+        .unwrap();
+    assert_eq!(first_line, "id\tname\n");
+
+    writer.join().unwrap().unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).is_empty());
+}
+
+#[test]
+fn test_output_file_infers_format_from_extension() {
+    let output = "test_infer_output.csv";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-O", output])
+        .write_stdin("id\tname\n1\tAlice Smith")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+
+    assert_eq!(
+        fs::read_to_string(output).unwrap(),
+        "id,name\n1,Alice Smith\n"
+    );
+
+    // An explicit -o wins over the extension
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-O", output, "-o", "tsv"])
+        .write_stdin("id,name\n1,Alice")
+        .assert()
+        .success();
+
+    assert_eq!(fs::read_to_string(output).unwrap(), "id\tname\n1\tAlice\n");
+
+    fs::remove_file(output).unwrap();
+}
+
+#[test]
+fn test_json_output_key_order_and_pretty() {
+    let input = "zeta,alpha,mid\n1,2,3";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "json"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("[{\"zeta\":\"1\",\"alpha\":\"2\",\"mid\":\"3\"}]\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "json", "--pretty"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout(
+            "[\n  {\n    \"zeta\": \"1\",\n    \"alpha\": \"2\",\n    \"mid\": \"3\"\n  }\n]\n",
+        );
+}
+
+#[test]
+fn test_output_file_json_extension() {
+    let output = "test_json_output.json";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-O", output])
+        .write_stdin("id,name\n1,Alice")
+        .assert()
+        .success();
+
+    assert_eq!(
+        fs::read_to_string(output).unwrap(),
+        "[{\"id\":\"1\",\"name\":\"Alice\"}]\n"
+    );
+
+    fs::remove_file(output).unwrap();
+}
+
+#[test]
+fn test_strip_list_markers() {
+    let input = "- | id | name |\n- |----|-------|\n- | 1 | Alice |\n- | 2 | Bob |";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--strip-list-markers")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n2\tBob\n");
+}
+
+#[test]
+fn test_transpose_drops_markdown_alignment() {
+    let input = "| id | price |\n|:--:|------:|\n| 1 | 9.99 |\n| 2 | 12.50 |";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--transpose", "-o", "debug"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "headers: [column] [row_1] [row_2]",
+        ))
+        .stdout(predicate::str::contains("row 2: [price] [9.99] [12.50]"))
+        .stdout(predicate::str::contains(":-").not())
+        .stdout(predicate::str::contains("-:").not());
+}
+
+#[test]
+fn test_input_flag_matches_positional() {
+    let path = "test_input_flag.csv";
+    fs::write(path, "id,name\n1,Alice").unwrap();
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    let positional = cmd.arg(path).assert().success().get_output().stdout.clone();
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--input", path])
+        .assert()
+        .success()
+        .stdout(positional.clone());
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-I", path]).assert().success().stdout(positional);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_input_flag_conflicts_with_positional() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--input", "a.csv", "b.csv"])
+        .assert()
+        .failure()
+        .code(2);
+}
+
+#[test]
+fn test_fill_down() {
+    let input = "category,item\nFruit,Apple\n,Pear\nVeg,Kale\n,Leek";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--fill-down", "category"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("category\titem\nFruit\tApple\nFruit\tPear\nVeg\tKale\nVeg\tLeek\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--fill-down", "missing"])
+        .write_stdin(input)
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("Column not found: 'missing'"));
+}
+
+#[test]
+fn test_keep_going_skips_bad_rows() {
+    let input = "id,name\n1,Alice\n2,Bob\n3,Carol,extra\n4,Dave\n5,Eve";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.write_stdin(input).assert().failure().code(1);
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    let assert = cmd
+        .arg("--keep-going")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n2\tBob\n4\tDave\n5\tEve\n");
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).to_string();
+    assert_eq!(
+        stderr,
+        "tabx: warning: Skipping line 4: expected 2 columns, found 3\n"
+    );
+}
+
+#[test]
+fn test_keep_going_markdown() {
+    let input = "| id | name |\n|----|------|\n| 1 | Alice |\n| 2 |\n| 3 | Carol |";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--keep-going")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n3\tCarol\n")
+        .stderr(predicate::str::contains("Skipping line 4"));
+}
+
+#[test]
+fn test_assert_no_empty() {
+    let input = "id,email\n1,a@example.com\n2,\n3,c@example.com";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--assert-no-empty", "id"])
+        .write_stdin(input)
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--assert-no-empty", "id,email"])
+        .write_stdin(input)
+        .assert()
+        .failure()
+        .code(1)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains(
+            "Column 'email' has 1 empty cell(s), first at row 2",
+        ));
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--assert-no-empty", "phone"])
+        .write_stdin(input)
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("Column not found: 'phone'"));
+}
+
+#[test]
+fn test_detect_lines_reaches_table_after_banner() {
+    let mut input: String = (1..=34)
+        .map(|i| format!("Query log entry {}\n", i))
+        .collect();
+    input.push_str(
+        "+----+-------+\n| id | name  |\n+----+-------+\n|  1 | Alice |\n+----+-------+\n",
+    );
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "debug"])
+        .write_stdin(input.clone())
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("format: csv (detected)"));
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--detect-lines", "40"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n");
+}
+
+#[test]
+fn test_detect_threshold_falls_back_to_csv() {
+    // The separator sits below the first data row, so Markdown scores only 0.75
+    let input = "| id | name  |\n| 1  | Alice |\n|----|-------|\n| 2  | Bob   |\n";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--detect-threshold", "0.5"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\tname\n2\tBob\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--detect-threshold", "0.9"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout(input)
+        .stderr(predicate::str::contains(
+            "Detected markdown with confidence 0.75, below the threshold of 0.90; reading as CSV",
+        ));
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--detect-threshold", "1.5"])
+        .write_stdin(input)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must be between 0.0 and 1.0"));
+}
+
+const XSV_FIXTURE: &str = "id,name,country\n1,Alice,US\n2,Bob,UK\n3,Carol,US\n4,Dave,FR\n5,Eve,US";
+
+#[test]
+fn test_select_subcommand() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["select", "country,id"])
+        .write_stdin(XSV_FIXTURE)
+        .assert()
+        .success()
+        .stdout("country\tid\nUS\t1\nUK\t2\nUS\t3\nFR\t4\nUS\t5\n");
+}
+
+#[test]
+fn test_frequency_subcommand() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["frequency", "-s", "country"])
+        .write_stdin(XSV_FIXTURE)
+        .assert()
+        .success()
+        .stdout("field\tvalue\tcount\ncountry\tUS\t3\ncountry\tFR\t1\ncountry\tUK\t1\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["frequency", "--select", "country", "--limit", "1"])
+        .write_stdin(XSV_FIXTURE)
+        .assert()
+        .success()
+        .stdout("field\tvalue\tcount\ncountry\tUS\t3\n");
+}
+
+#[test]
+fn test_slice_subcommand() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["slice", "-s", "1", "-l", "2"])
+        .write_stdin(XSV_FIXTURE)
+        .assert()
+        .success()
+        .stdout("id\tname\tcountry\n2\tBob\tUK\n3\tCarol\tUS\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["slice", "--start", "3", "--end", "10"])
+        .write_stdin(XSV_FIXTURE)
+        .assert()
+        .success()
+        .stdout("id\tname\tcountry\n4\tDave\tFR\n5\tEve\tUS\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["slice", "--index", "0"])
+        .write_stdin(XSV_FIXTURE)
+        .assert()
+        .success()
+        .stdout("id\tname\tcountry\n1\tAlice\tUS\n");
+}
+
+#[test]
+fn test_delimiter_conflict_policies() {
+    let input = "id,note\n1,\"a\tb\"";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "csv", "--delimiter-conflict", "error"])
+        .write_stdin(input)
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "Row 1 contains delimiter character",
+        ));
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "csv", "--delimiter-conflict", "quote"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\tnote\n1\t\"a\tb\"\n")
+        .stderr(predicate::str::contains("quoting fields CSV-style"));
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "csv", "--delimiter-conflict", "strip"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\tnote\n1\tab\n")
+        .stderr(predicate::str::contains("Removed delimiter '\\t'"));
+}
+
+#[test]
+fn test_delimiter_conflict_strip_custom_delimiter_in_header() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-d", "|", "--delimiter-conflict", "strip"])
+        .write_stdin("id,name|alias\n1,Al|Bo")
+        .assert()
+        .success()
+        .stdout("id|namealias\n1|AlBo\n");
+}
+
+#[test]
+fn test_columns_rename_in_place() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--columns", "id,full_name=name,email"])
+        .write_stdin("email,name,id\na@x.com,Alice,1\nb@x.com,Bob,2")
+        .assert()
+        .success()
+        .stdout("id\tfull_name\temail\n1\tAlice\ta@x.com\n2\tBob\tb@x.com\n");
+}
+
+#[test]
+fn test_columns_rename_unknown_source() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--columns", "id,full_name=nmae"])
+        .write_stdin("id,name\n1,Alice")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("Column not found: 'nmae'"));
+}
+
+#[test]
+fn test_columns_rename_duplicated_source() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--columns", "name,alias=name"])
+        .write_stdin("id,name\n1,Alice")
+        .assert()
+        .success()
+        .stdout("name\talias\nAlice\tAlice\n");
+}
+
+#[test]
+fn test_columns_rename_wildcard_source() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--columns", "n=na*"])
+        .write_stdin("id,name\n1,Alice")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "Cannot rename 'na*': the source must be a single column, not a wildcard",
+        ));
+}
+
+#[test]
+fn test_lines_input_format() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "lines"])
+        .write_stdin("apple\nbanana\n\ncherry\n")
+        .assert()
+        .success()
+        .stdout("value\napple\nbanana\ncherry\n");
+}
+
+#[test]
+fn test_plain_lines_auto_detection_warns() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.write_stdin("apple\nbanana\ncherry")
+        .assert()
+        .success()
+        .stdout("apple\nbanana\ncherry\n")
+        .stderr(predicate::str::contains("use -i lines"));
+}
+
+#[test]
+fn test_strip_table_prefix() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--strip-table-prefix")
+        .write_stdin("users.id,users.name,orders.id\n1,Alice,10")
+        .assert()
+        .success()
+        .stdout("id\tname\tid_2\n1\tAlice\t10\n");
+}
+
+#[test]
+fn test_tree_output() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "tree"])
+        .write_stdin("id,name,email\n1,Alice,alice@example.com")
+        .assert()
+        .success()
+        .stdout("Row 1\n├─ id: 1\n├─ name: Alice\n└─ email: alice@example.com\n");
+}
+
+#[test]
+fn test_reject_empty_headers() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--reject-empty-headers")
+        .write_stdin(",name\n1,Alice")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("Header of column 1 is empty"));
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.write_stdin(",name\n1,Alice")
+        .assert()
+        .success()
+        .stdout("\tname\n1\tAlice\n");
+}
+
+#[test]
+fn test_markdown_output() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "markdown"])
+        .write_stdin("id,expr\n1,a|b\n2,c")
+        .assert()
+        .success()
+        .stdout("| id | expr |\n|---|---|\n| 1 | a\\|b |\n| 2 | c |\n");
+}
+
+#[test]
+fn test_markdown_output_round_trips() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    let output = cmd
+        .args(["-o", "markdown"])
+        .write_stdin("id,name\n1,Alice\n2,Bob")
+        .output()
+        .unwrap();
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.write_stdin(output.stdout)
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n2\tBob\n");
+}
+
+#[test]
+fn test_output_file_markdown_extension() {
+    let output = "test_markdown_output.md";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-O", output])
+        .write_stdin("id,name\n1,Alice")
+        .assert()
+        .success();
+
+    assert_eq!(
+        fs::read_to_string(output).unwrap(),
+        "| id | name |\n|---|---|\n| 1 | Alice |\n"
+    );
+
+    fs::remove_file(output).unwrap();
+}
+
+#[test]
+fn test_sort_numeric_and_lexical_overrides() {
+    let input = "id,name\n010,Ten\n007,Seven\n9,Nine";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--sort", "id"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\tname\n007\tSeven\n9\tNine\n010\tTen\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--sort-lexical", "id"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\tname\n007\tSeven\n010\tTen\n9\tNine\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--sort-numeric", "id"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout("id\tname\n007\tSeven\n9\tNine\n010\tTen\n");
+}
+
+#[test]
+fn test_sort_options_conflict() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--sort", "id", "--sort-lexical", "id"])
+        .write_stdin("id\n1")
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn test_json_output_duplicate_headers_and_escaping() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "json"])
+        .write_stdin("name,name,note\nAlice,Smith,\"say \"\"hi\"\"\\now\"")
+        .assert()
+        .success()
+        .stdout("[{\"name\":\"Alice\",\"name_2\":\"Smith\",\"note\":\"say \\\"hi\\\"\\\\now\"}]\n");
+}
+
+#[test]
+fn test_explode_column() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--explode", "tags"])
+        .write_stdin("id,tags\n1,a;b;c\n2,")
+        .assert()
+        .success()
+        .stdout("id\ttags\n1\ta\n1\tb\n1\tc\n2\t\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--explode", "tags", "--explode-sep", "|"])
+        .write_stdin("id,tags\n1,x|y")
+        .assert()
+        .success()
+        .stdout("id\ttags\n1\tx\n1\ty\n");
+}
+
+#[test]
+fn test_file_input_unreadable_path() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("src")
+        .write_stdin("id\n1")
+        .assert()
+        .code(3)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::starts_with(
+            "tabx: error: Cannot read src: ",
+        ));
+}
+
+#[test]
+fn test_verify_csv_round_trip() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("verify")
+        .write_stdin("id,note\n1,\"a, b\"\n2,\"say \"\"hi\"\"\"")
+        .assert()
+        .success()
+        .stdout("csv round trip OK: 2 rows, 2 columns\n");
+}
+
+#[test]
+fn test_verify_tsv_round_trip() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("verify")
+        .write_stdin("id\tname\n1\tAlice\n2\tBob")
+        .assert()
+        .success()
+        .stdout("tsv round trip OK: 2 rows, 2 columns\n");
+}
+
+#[test]
+fn test_verify_format_without_writer_fails() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "org", "verify"])
+        .write_stdin("| id | name  |\n|----+-------|\n| 1  | Alice |\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("There is no org writer"));
+}
+
+#[test]
+fn test_verify_tsv_embedded_tab_fails() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "tsv", "verify"])
+        .write_stdin("id\tnote\n1\t\"a\tb\"")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("Verification failed"))
+        .stderr(predicate::str::contains(
+            "Row 1 contains delimiter character",
+        ));
+}
+
+#[test]
+fn test_sql_output() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "sql", "--sql-table", "users"])
+        .write_stdin("id,last name\n1,O'Brien\n2,Smith")
+        .assert()
+        .success()
+        .stdout(
+            "INSERT INTO users (id, \"last name\") VALUES ('1', 'O''Brien');\n\
+             INSERT INTO users (id, \"last name\") VALUES ('2', 'Smith');\n",
+        );
+}
+
+const HTML_PAGE: &str = "<table><tr><td>Home</td><td>About</td></tr></table>\n\
+<table><tr><th>id</th><th>name</th></tr><tr><td>1</td><td>Alice</td></tr></table>";
+
+#[test]
+fn test_html_input_table_index() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "html"])
+        .write_stdin(HTML_PAGE)
+        .assert()
+        .success()
+        .stdout("Home\tAbout\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "html", "--table-index", "1"])
+        .write_stdin(HTML_PAGE)
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n");
+}
+
+#[test]
+fn test_html_input_select_table_with_header() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "html", "--select-table-with-header", "name,id"])
+        .write_stdin(HTML_PAGE)
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "html", "--select-table-with-header", "email"])
+        .write_stdin(HTML_PAGE)
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("No table has the headers: email"));
+}
+
+#[test]
+fn test_psv_round_trip() {
+    let csv = "id,name,note\n1,Alice,\"a, b\"\n2,Bob,\n";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    let psv = cmd
+        .args(["-o", "psv"])
+        .write_stdin(csv)
+        .assert()
+        .success()
+        .stdout("id|name|note\n1|Alice|a, b\n2|Bob|\n")
+        .get_output()
+        .stdout
+        .clone();
+
+    // Detected as PSV without -i
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "csv"])
+        .write_stdin(psv)
+        .assert()
+        .success()
+        .stdout(csv);
+}
+
+#[test]
+fn test_psv_input_alias_and_conflict() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "pipe"])
+        .write_stdin("id|name\n1|Alice\n")
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "csv", "-o", "psv"])
+        .write_stdin("id,cmd\n1,a|b\n")
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_bom_stripped_before_detection() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.write_stdin("\u{FEFF}| id | name |\n|----|------|\n| 1  | Alice |\n")
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--columns", "id"])
+        .write_stdin("\u{FEFF}id,name\n1,Alice\n")
+        .assert()
+        .success()
+        .stdout("id\n1\n");
+}
+
+#[test]
+fn test_comment_header() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    let output = cmd
+        .arg("--comment-header")
+        .write_stdin("id,name\n1,Alice\n2,Bob\n")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let output = String::from_utf8(output).unwrap();
+    let mut lines = output.lines();
+    assert_eq!(lines.next(), Some("#id\tname"));
+    assert!(lines.all(|line| !line.starts_with('#')));
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--comment-header=;", "-o", "csv"])
+        .write_stdin("id,name\n1,Alice\n")
+        .assert()
+        .success()
+        .stdout(";id,name\n1,Alice\n");
+}
+
+#[test]
+fn test_org_table_input() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.write_stdin("| id | name  |\n|----+-------|\n|  1 | Alice |\n|  2 | Bob   |\n")
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n2\tBob\n");
+}
+
+#[test]
+fn test_map_file_remaps_column() {
+    let map_path = "test_status_map.csv";
+    fs::write(map_path, "from,to\nA,active\nI,inactive\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--map-file")
+        .arg(format!("status={}", map_path))
+        .write_stdin("id,status\n1,A\n2,I\n3,X\n4,A\n")
+        .assert()
+        .success()
+        .stdout("id\tstatus\n1\tactive\n2\tinactive\n3\tX\n4\tactive\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--map-file")
+        .arg(format!("missing={}", map_path))
+        .write_stdin("id,status\n1,A\n")
+        .assert()
+        .code(2);
+
+    fs::remove_file(map_path).unwrap();
+}
+
+#[test]
+fn test_output_file_cannot_be_opened() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-O", "no_such_dir/out.tsv"])
+        .write_stdin("id,name\n1,Alice\n")
+        .assert()
+        .code(3)
+        .stdout("")
+        .stderr(predicate::str::starts_with(
+            "tabx: error: Cannot write no_such_dir/out.tsv: ",
+        ));
+}
+
+#[test]
+fn test_jsonl_typed_output() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "jsonl-typed"])
+        .write_stdin("id,active\n1,true\n2,false\n")
+        .assert()
+        .success()
+        .stdout("{\"id\":1,\"active\":true}\n{\"id\":2,\"active\":false}\n");
+
+    // One non-numeric id keeps the whole column as strings
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "jsonl-typed"])
+        .write_stdin("id,active\n1,true\nx2,\n")
+        .assert()
+        .success()
+        .stdout("{\"id\":\"1\",\"active\":true}\n{\"id\":\"x2\",\"active\":null}\n");
+}
+
+#[test]
+fn test_parse_error_reports_input_line() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.write_stdin("| id | name |\n|----|------|\n| 1 | Alice |\n| 2 |\n")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::starts_with(
+            "tabx: error: Line 4: Inconsistent column count at row 2",
+        ));
+}
+
+const PEOPLE: &str = "name,status,age\nAlice,active,34\nBob,inactive,41\nCarol,active,28\n";
+
+#[test]
+fn test_select_rows_and() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--select-rows", "status==active && age>30"])
+        .write_stdin(PEOPLE)
+        .assert()
+        .success()
+        .stdout("name\tstatus\tage\nAlice\tactive\t34\n");
+}
+
+#[test]
+fn test_select_rows_or() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--select-rows", "name==Bob || age<30"])
+        .write_stdin(PEOPLE)
+        .assert()
+        .success()
+        .stdout("name\tstatus\tage\nBob\tinactive\t41\nCarol\tactive\t28\n");
+}
+
+#[test]
+fn test_select_rows_parentheses() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args([
+        "--select-rows",
+        "(name==Bob || status==active) && age>=34",
+        "-c",
+        "name",
+    ])
+    .write_stdin(PEOPLE)
+    .assert()
+    .success()
+    .stdout("name\nAlice\nBob\n");
+}
+
+#[test]
+fn test_select_rows_syntax_error() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--select-rows", "(age>30"])
+        .write_stdin(PEOPLE)
+        .assert()
+        .code(2)
+        .stderr("tabx: error: Invalid row selection: unmatched '('\n");
+}
+
+#[test]
+fn test_select_rows_syntax_error_before_reading_input() {
+    // The expression is rejected before the missing file is opened
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--select-rows", "age>", "no_such_file.csv"])
+        .assert()
+        .code(2)
+        .stderr("tabx: error: Invalid row selection: expected a value after 'age'\n");
+}
+
+#[test]
+fn test_mysql_output_round_trips() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    let boxed = cmd
+        .args(["-o", "mysql"])
+        .write_stdin("id,name\n1,Alice\n22,Bob\n")
+        .assert()
+        .success()
+        .stdout(
+            "+----+-------+\n| id | name  |\n+----+-------+\n| 1  | Alice |\n| 22 | Bob   |\n+----+-------+\n",
+        )
+        .get_output()
+        .stdout
+        .clone();
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("verify").write_stdin(boxed).assert().success();
+}
+
+#[test]
+fn test_preserve_comments_reemits_stripped_lines() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--comment", "#", "--preserve-comments", "-o", "csv"])
+        .write_stdin("# Licensed under MIT\nid,name\n# exported 2024-01-01\n1,Alice\n")
+        .assert()
+        .success()
+        .stdout("# Licensed under MIT\n# exported 2024-01-01\nid,name\n1,Alice\n");
+
+    // Without --preserve-comments the lines are only dropped
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--comment", "#"])
+        .write_stdin("# Licensed under MIT\nid,name\n1,Alice\n")
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n");
+}
+
+#[test]
+fn test_retab_realigns_broken_box() {
+    let input = "+--+---+\n| id | name |\n+--+---+\n| 1 | Alice |\n|  22   | Bob |\n+--+---+\n";
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.arg("--retab")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout(
+            "+----+-------+\n| id | name  |\n+----+-------+\n| 1  | Alice |\n| 22 | Bob   |\n+----+-------+\n",
+        );
+}
+
+#[test]
+fn test_retab_conflicts_with_output_format() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--retab", "-o", "csv"])
+        .write_stdin("id,name\n1,Alice\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_for_excel_writes_bom_and_crlf_csv() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--for", "excel"])
+        .write_stdin("id\tname\n1\tAlice\n")
+        .assert()
+        .success()
+        .stdout("\u{FEFF}id,name\r\n1,Alice\r\n");
+}
+
+#[test]
+fn test_for_preset_yields_to_explicit_output_format() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--for", "sqlite", "-o", "tsv"])
+        .write_stdin("id,name\n1,Alice\n")
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n");
+
+    // The Excel preset's BOM and CRLF are dropped with its format, so nothing warns
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--for", "excel", "-o", "tsv"])
+        .write_stdin("id,name\n1,Alice\n")
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n")
+        .stderr("");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--for", "excel", "-o", "json"])
+        .write_stdin("id,name\n1,Alice\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("["))
+        .stderr("");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--for", "markdown"])
+        .write_stdin("id,name\n1,Alice\n")
+        .assert()
+        .success()
+        .stdout("| id | name |\n|---|---|\n| 1 | Alice |\n");
+}
+
+#[test]
+fn test_bom_warns_for_non_csv_output() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--bom", "-o", "json"])
+        .write_stdin("id,name\n1,Alice\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("["))
+        .stderr(predicate::str::contains("--bom only applies to csv output"));
+}
+
+#[test]
+fn test_skip_rows_drops_banner_before_detection() {
+    // Without skipping, the banner would become the CSV header
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--skip-rows", "1"])
+        .write_stdin("Connected to db.example.com\nid,name\n1,Alice\n")
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n");
+}
+
+#[test]
+fn test_skip_trailing_drops_psql_footer() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--skip-rows", "2", "--skip-trailing", "1"])
+        .write_stdin("psql (16.2)\n\n id | name\n----+-------\n  1 | Alice\n(1 row)\n\n")
+        .assert()
+        .success()
+        .stdout("id\tname\n1\tAlice\n");
+}
+
+#[test]
+fn test_require_columns_allows_extra_columns() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--require-columns", "email,id"])
+        .write_stdin("id,name,email\n1,Alice,a@example.com\n")
+        .assert()
+        .success()
+        .stdout("id\tname\temail\n1\tAlice\ta@example.com\n");
+}
+
+#[test]
+fn test_require_columns_lists_missing_columns() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--require-columns", "id, email,phone"])
+        .write_stdin("id,name\n1,Alice\n")
+        .assert()
+        .code(1)
+        .stdout("")
+        .stderr("tabx: error: Missing required column(s): email, phone\n");
+}
+
+#[test]
+fn test_ndjson_output_writes_one_object_per_line() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "ndjson"])
+        .write_stdin("id,name\n1,\"Smith, \"\"Al\"\"\"\n2,Zoë\n")
+        .assert()
+        .success()
+        .stdout("{\"id\":\"1\",\"name\":\"Smith, \\\"Al\\\"\"}\n{\"id\":\"2\",\"name\":\"Zoë\"}\n");
+}
+
+#[test]
+fn test_melt_wide_table_to_long_form() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args([
+        "--melt",
+        "store,year",
+        "--melt-var",
+        "quarter",
+        "--melt-value",
+        "sales",
+        "-o",
+        "csv",
+    ])
+    .write_stdin("store,year,q1,q2,q3\nnorth,2023,10,12,9\nsouth,2023,7,8,11\n")
+    .assert()
+    .success()
+    .stdout(
+        "store,year,quarter,sales\n\
+         north,2023,q1,10\nnorth,2023,q2,12\nnorth,2023,q3,9\n\
+         south,2023,q1,7\nsouth,2023,q2,8\nsouth,2023,q3,11\n",
+    );
+}
+
+#[test]
+fn test_melt_unknown_id_column() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["--melt", "region"])
+        .write_stdin("store,q1\nnorth,10\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("region"));
+}
+
+#[test]
+fn test_json_input_is_detected() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.write_stdin("[{\"id\": 1, \"name\": \"Alice\"}, {\"id\": 2, \"admin\": true}]\n")
+        .assert()
+        .success()
+        .stdout("id\tname\tadmin\n1\tAlice\t\n2\t\ttrue\n");
+
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-o", "csv"])
+        .write_stdin("[[\"id\",\"name\"],[1,\"Alice\"]]")
+        .assert()
+        .success()
+        .stdout("id,name\n1,Alice\n");
+}
+
+#[test]
+fn test_tsv_output_lists_every_tab_conflict() {
+    let mut cmd = Command::cargo_bin("tabx").unwrap();
+    cmd.args(["-i", "csv"])
+        .write_stdin("id,name,note\n1,\"Al\tice\",ok\n2,Bob,fine\n3,\"Car\tol\",\"a\tb\"\n")
+        .assert()
+        .code(1)
+        .stdout("")
+        .stderr(
+            "tabx: error: Row 1 contains delimiter character '\\t' in column 'name'\n\
+             tabx: error: Row 3 contains delimiter character '\\t' in column 'name'\n\
+             tabx: error: Row 3 contains delimiter character '\\t' in column 'note'\n\
+             tabx: error: Found delimiter character '\\t' in 3 place(s). Use -o csv for proper escaping.\n",
+        );
+}